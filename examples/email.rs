@@ -5,7 +5,7 @@ extern crate rust_regex_dsl;
 use rust_regex_dsl::create_capture;
 use std::io::BufRead;
 
-create_capture!(Email, concat {
+create_capture!(Email, owned, concat {
     group {
         name: user_name,
         repeat {
@@ -46,14 +46,16 @@ create_capture!(Email, concat {
 });
 fn main() {
     let stdin = std::io::stdin();
+    let mut emails: Vec<EmailOwned> = vec![];
     for line in stdin.lock().lines() {
-        for email in Email::catch_all(&line.unwrap()) {
-            println!(
-                "Got email: {} - user: {}, host: {}",
-                email.0,
-                email.user_name().unwrap(),
-                email.host().unwrap()
-            );
-        }
+        emails.extend(Email::catch_all(&line.unwrap()).map(Email::into_owned));
+    }
+    for email in emails {
+        println!(
+            "Got email: {} - user: {}, host: {}",
+            email.get_capture(),
+            email.user_name(),
+            email.host()
+        );
     }
 }
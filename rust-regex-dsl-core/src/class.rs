@@ -0,0 +1,136 @@
+use itertools::Itertools;
+
+use crate::dsl::{single_word, Dsl};
+use crate::error::DslError;
+use crate::predefined_class::PredefineClass;
+
+/// A user class, see [`Dsl::any_of`] and [`Dsl::not_any_of`].
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl_core::{Class, Dsl};
+///
+/// let class = Class::new()
+///     .class("Greek")
+///     .unwrap()
+///     .range('a', 'f')
+///     .chars("_-")
+///     .subtract(Class::new().chars("e"));
+/// let regex = Dsl::any_of(class).build().unwrap();
+/// assert!(regex.is_match("α"));
+/// assert!(regex.is_match("a"));
+/// assert!(regex.is_match("-"));
+/// assert!(!regex.is_match("e"));
+/// assert!(!regex.is_match("z"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Class {
+    contains: String,
+}
+
+trait Escape {
+    fn escape(&self) -> String;
+}
+impl Escape for char {
+    fn escape(&self) -> String {
+        match self {
+            '-' | ']' | '[' | '^' | '&' | '.' | '\\' => format!("\\{}", self),
+            _ => format!("{}", self),
+        }
+    }
+}
+impl Escape for str {
+    fn escape(&self) -> String {
+        self.chars().unique().map(|c| c.escape()).join("")
+    }
+}
+
+impl Class {
+    pub fn new() -> Self {
+        Class::default()
+    }
+
+    /// All the characters in the string.
+    pub fn chars(mut self, chars: &str) -> Self {
+        self.contains.push_str(&chars.escape());
+        self
+    }
+
+    /// A single character.
+    pub fn char(mut self, chr: char) -> Self {
+        self.contains.push_str(&chr.escape());
+        self
+    }
+
+    /// All the characters in the range (inclusive).
+    pub fn range(mut self, from: char, to: char) -> Self {
+        self.contains
+            .push_str(&format!("{}-{}", from.escape(), to.escape()));
+        self
+    }
+
+    /// All the characters in a predefined unicode class (i.e. `#<name>` in the macro).
+    pub fn class(self, name: &str) -> Result<Self, DslError> {
+        Ok(self.predefined(&PredefineClass::new(name, true)?))
+    }
+
+    /// All the characters that are not in a predefined unicode class (i.e. `~<name>` in the macro).
+    pub fn not_class(self, name: &str) -> Result<Self, DslError> {
+        Ok(self.predefined(&PredefineClass::new(name, false)?))
+    }
+
+    /// All the characters of a single word, like `digit` or `white_space`.
+    pub fn word(mut self, word: &str) -> Result<Self, DslError> {
+        let Some(regex) = single_word(word) else {
+            return Err(DslError::new(format!("Unknown word: {}", word)));
+        };
+        self.contains.push_str(&regex);
+        Ok(self)
+    }
+
+    /// All the characters in the other class (i.e. a nested `any_of` in the macro).
+    pub fn any_of(mut self, other: Class) -> Self {
+        self.contains.push_str(&format!("[{}]", other.contains));
+        self
+    }
+
+    /// All the characters that are not in the other class (i.e. a nested `not_any_of` in the macro).
+    pub fn not_any_of(mut self, other: Class) -> Self {
+        self.contains.push_str(&format!("[^{}]", other.contains));
+        self
+    }
+
+    /// Only the characters that are also in the other class.
+    pub fn intersect(mut self, other: Class) -> Self {
+        self.contains.push_str(&format!("&&[{}]", other.contains));
+        self
+    }
+
+    /// Only the characters that are not in the other class.
+    pub fn subtract(mut self, other: Class) -> Self {
+        self.contains.push_str(&format!("&&[^{}]", other.contains));
+        self
+    }
+
+    /// Only the characters that are in one of the classes, but not in both.
+    pub fn xor(mut self, other: Class) -> Self {
+        self.contains.push_str(&format!("~~[{}]", other.contains));
+        self
+    }
+
+    pub(crate) fn predefined(mut self, class: &PredefineClass) -> Self {
+        self.contains.push_str(&class.regex);
+        self
+    }
+
+    #[cfg(feature = "parse")]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.contains.is_empty()
+    }
+
+    pub(crate) fn to_dsl(&self, use_me: bool) -> Dsl {
+        let prefix = if use_me { "" } else { "^" };
+        let regex = format!("[{}{}]", prefix, self.contains);
+        Dsl::new(&regex, false)
+    }
+}
@@ -0,0 +1,543 @@
+use itertools::Itertools;
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+use regex::Regex;
+#[cfg(feature = "parse")]
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Ident, LitChar, LitStr, Token,
+};
+
+use crate::{class::Class, error::DslError, flavor::Flavor, predefined_class::PredefineClass};
+#[cfg(feature = "parse")]
+use crate::{ident_parser::parse_ident, options::RegexOptions};
+
+/// A regular expression DSL.
+///
+/// This is the runtime equivalent of the `regex_dsl!` macro, every function of the macro has an equivalent function here.
+/// For example:
+/// ```rust
+/// use rust_regex_dsl_core::{Class, Dsl};
+///
+/// let dsl = Dsl::concat([
+///     Dsl::eq("id-"),
+///     Dsl::times(Dsl::any_of(Class::new().range('0', '9'))).at_least(2).into(),
+///     Dsl::maybe(Dsl::named_group("suffix", Dsl::eq(".x"))).into(),
+/// ]);
+/// let regex = dsl.build().unwrap();
+/// assert!(regex.is_match("id-123"));
+/// assert!(!regex.is_match("id-1"));
+/// assert_eq!(&regex.captures("id-12.x").unwrap()["suffix"], ".x");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dsl {
+    regex: String,
+    need_group: bool,
+    #[cfg(feature = "parse")]
+    group_types: Vec<GroupType>,
+}
+
+#[cfg(feature = "parse")]
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct GroupType {
+    pub name: String,
+    pub group_type: TokenStream,
+}
+
+/// A repetition of a DSL, see [`Dsl::times`].
+#[derive(Debug, Clone)]
+pub struct Times {
+    dsl: Dsl,
+    at_least: usize,
+    at_most: Option<usize>,
+    lazy: bool,
+}
+
+/// A regular expression flag, see [`Apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flag {
+    /// Letters match both upper and lower case - equivalent to the `i` flag.
+    CaseInsensitive,
+    /// `beginning_of_line` and `end_of_line` match begin/end of line and not input - equivalent to the `m` flag.
+    MultiLine,
+    /// Allow `any_character` to match a new line - equivalent to the `s` flag.
+    AllowDot,
+    /// When `MultiLine` flag is set, `\r\n` is used - equivalent to the `R` flag.
+    EnablesCrlfMode,
+    /// Swap the meaning of lazy and greedy repetitions - equivalent to the `U` flag.
+    SwapGreed,
+    /// Use unicode classes and characters (on by default) - equivalent to the `u` flag.
+    Unicode,
+}
+
+/// Regular expression flags to apply, see [`Dsl::apply`].
+#[derive(Debug, Clone, Default)]
+pub struct Apply {
+    flags_to_add: Vec<Flag>,
+    flags_to_remove: Vec<Flag>,
+}
+
+impl Dsl {
+    #[doc(hidden)]
+    pub fn new(regex: &str, need_group: bool) -> Self {
+        Dsl {
+            regex: regex.into(),
+            need_group,
+            #[cfg(feature = "parse")]
+            group_types: vec![],
+        }
+    }
+
+    /// A literal string (i.e. `eq` or a string literal in the macro).
+    pub fn eq(string: &str) -> Self {
+        let need_group = string.len() > 1;
+        let regex: String = string
+            .chars()
+            .map(|c| match c {
+                '\\' | '\"' | '.' | ')' | '(' | '{' | '}' | '[' | ']' | '$' | '^' | '?' | '+'
+                | '*' | '|' => {
+                    format!("\\{}", c)
+                }
+                _ => format!("{}", c),
+            })
+            .collect();
+
+        Dsl::new(&regex, need_group)
+    }
+
+    /// A raw regular expression (i.e. `regex` in the macro).
+    pub fn regex(regex: &str) -> Result<Self, DslError> {
+        let dsl = Dsl::new(regex, regex.len() > 1);
+        // The final regular expression is validated with the actual flavor.
+        if let Some(err) = dsl.validate_any() {
+            return Err(DslError::new(err));
+        }
+        Ok(dsl)
+    }
+
+    /// A single word, like `digit` or `word_boundary`.
+    pub fn word(word: &str) -> Result<Self, DslError> {
+        match single_word(word) {
+            Some(regex) => Ok(Dsl::new(&regex, false)),
+            None => Err(DslError::new(format!("Unknown word: {}", word))),
+        }
+    }
+
+    /// A predefined unicode class (i.e. `#<name>` in the macro).
+    pub fn class(name: &str) -> Result<Self, DslError> {
+        Ok(PredefineClass::new(name, true)?.to_dsl())
+    }
+
+    /// Anything but a predefined unicode class (i.e. `~<name>` in the macro).
+    pub fn not_class(name: &str) -> Result<Self, DslError> {
+        Ok(PredefineClass::new(name, false)?.to_dsl())
+    }
+
+    /// A single byte, only valid for bytes regular expression.
+    pub fn byte(byte: u8) -> Self {
+        Dsl::new(&format!("(?-u:\\x{:02X})", byte), false)
+    }
+
+    /// All the DSLs, one after the other.
+    pub fn concat(dsls: impl AsRef<[Dsl]>) -> Self {
+        let dsls = dsls.as_ref();
+        let need_group = match dsls.len() {
+            0 => false,
+            1 => dsls[0].need_group,
+            _ => true,
+        };
+        let regex: String = dsls
+            .iter()
+            .map(|dsl| dsl.non_capturing_group_if_needed())
+            .collect();
+        Dsl::new(&regex, need_group).with_group_types_of(dsls)
+    }
+
+    /// Any one of the DSLs.
+    pub fn any(dsls: impl AsRef<[Dsl]>) -> Self {
+        let dsls = dsls.as_ref();
+        let regex = dsls
+            .iter()
+            .map(Dsl::non_capturing_group_if_needed)
+            .join("|");
+        Dsl::new(&regex, true).with_group_types_of(dsls)
+    }
+
+    /// Any character in the class.
+    pub fn any_of(class: Class) -> Self {
+        class.to_dsl(true)
+    }
+
+    /// Any character that is not in the class.
+    pub fn not_any_of(class: Class) -> Self {
+        class.to_dsl(false)
+    }
+
+    /// A capture group.
+    pub fn group(dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture("")
+    }
+
+    /// A named capture group.
+    pub fn named_group(name: &str, dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture(&format!("?<{}>", name))
+    }
+
+    /// A match of the capture group `name` (i.e. `backreference` in the macro). Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn backreference(name: &str) -> Self {
+        Dsl::new(&format!("\\k<{}>", name), false)
+    }
+
+    /// A position that is followed by the DSL (i.e. `lookahead` in the macro). Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn lookahead(dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture("?=")
+    }
+
+    /// A position that is not followed by the DSL (i.e. `negative_lookahead` in the macro). Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn negative_lookahead(dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture("?!")
+    }
+
+    /// A position that is preceded by the DSL (i.e. `lookbehind` in the macro). Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn lookbehind(dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture("?<=")
+    }
+
+    /// A position that is not preceded by the DSL (i.e. `negative_lookbehind` in the macro). Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn negative_lookbehind(dsl: impl Into<Dsl>) -> Self {
+        dsl.into().capture("?<!")
+    }
+
+    /// The DSL, at least once.
+    pub fn repeat(dsl: impl Into<Dsl>) -> Times {
+        Dsl::times(dsl).at_least(1)
+    }
+
+    /// The DSL, any number of times (including zero).
+    pub fn maybe_repeat(dsl: impl Into<Dsl>) -> Times {
+        Dsl::times(dsl)
+    }
+
+    /// The DSL, at most once.
+    pub fn maybe(dsl: impl Into<Dsl>) -> Times {
+        Dsl::times(dsl).at_most(1)
+    }
+
+    /// The DSL, a number of times. Use the [`Times`] functions to set the number of times.
+    pub fn times(dsl: impl Into<Dsl>) -> Times {
+        Times {
+            dsl: dsl.into(),
+            at_least: 0,
+            at_most: None,
+            lazy: false,
+        }
+    }
+
+    /// Regular expression flags to apply. Use the [`Apply`] functions to set the flags.
+    pub fn apply() -> Apply {
+        Apply::default()
+    }
+
+    /// Build a regular expression from the DSL.
+    pub fn build(&self) -> Result<Regex, DslError> {
+        if let Some(err) = self.validate(Flavor::Str) {
+            return Err(DslError::new(err));
+        }
+        Regex::new(&self.regex).map_err(|e| DslError::new(e.to_string()))
+    }
+
+    /// Build a bytes regular expression from the DSL.
+    pub fn build_bytes(&self) -> Result<regex::bytes::Regex, DslError> {
+        if let Some(err) = self.validate(Flavor::Bytes) {
+            return Err(DslError::new(err));
+        }
+        regex::bytes::Regex::new(&self.regex).map_err(|e| DslError::new(e.to_string()))
+    }
+
+    /// Build a [`fancy_regex::Regex`] from the DSL. Only supported with the `fancy` feature.
+    #[cfg(feature = "fancy")]
+    pub fn build_fancy(&self) -> Result<fancy_regex::Regex, DslError> {
+        if let Some(err) = self.validate(Flavor::Fancy) {
+            return Err(DslError::new(err));
+        }
+        fancy_regex::Regex::new(&self.regex).map_err(|e| DslError::new(e.to_string()))
+    }
+
+    /// The regular expression of the DSL.
+    pub fn as_str(&self) -> &str {
+        &self.regex
+    }
+
+    #[doc(hidden)]
+    pub fn validate(&self, flavor: Flavor) -> Option<String> {
+        if self.regex.is_empty() {
+            return Some("Empty regex is not supported".to_string());
+        }
+        flavor
+            .validate(self.regex.as_str())
+            .map(|error| flavor.with_fancy_hint(error, self.regex.as_str()))
+    }
+
+    #[cfg(feature = "parse")]
+    #[doc(hidden)]
+    pub fn validate_with(&self, flavor: Flavor, options: &RegexOptions) -> Option<String> {
+        if self.regex.is_empty() {
+            return Some("Empty regex is not supported".to_string());
+        }
+        options
+            .capture_names(flavor, self.regex.as_str())
+            .map_err(|error| flavor.with_fancy_hint(error, self.regex.as_str()))
+            .err()
+    }
+
+    #[doc(hidden)]
+    pub fn validate_any(&self) -> Option<String> {
+        if self.regex.is_empty() {
+            return Some("Empty regex is not supported".to_string());
+        }
+        Flavor::validate_any(self.regex.as_str())
+    }
+
+    #[doc(hidden)]
+    #[cfg_attr(not(feature = "parse"), allow(unused_mut, unused_variables))]
+    pub fn with_group_types_of(mut self, dsls: &[Dsl]) -> Self {
+        #[cfg(feature = "parse")]
+        for dsl in dsls {
+            self.group_types.extend(dsl.group_types.iter().cloned());
+        }
+        self
+    }
+
+    #[cfg(feature = "parse")]
+    #[doc(hidden)]
+    pub fn with_group_type(mut self, name: &str, group_type: TokenStream) -> Self {
+        self.group_types.push(GroupType {
+            name: name.into(),
+            group_type,
+        });
+        self
+    }
+
+    #[cfg(feature = "parse")]
+    #[doc(hidden)]
+    pub fn group_types(&self) -> &[GroupType] {
+        &self.group_types
+    }
+
+    #[doc(hidden)]
+    pub fn non_capturing_group_if_needed(&self) -> String {
+        if !self.need_group {
+            self.regex.clone()
+        } else {
+            format!("(?:{})", self.regex)
+        }
+    }
+
+    fn capture(&self, name_prefix: &str) -> Self {
+        let regex = format!("({}{})", name_prefix, self.regex);
+        Self {
+            regex,
+            need_group: false,
+            #[cfg(feature = "parse")]
+            group_types: self.group_types.clone(),
+        }
+    }
+}
+
+impl Times {
+    /// Exactly `times` times.
+    pub fn exactly(mut self, times: usize) -> Self {
+        self.at_least = times;
+        self.at_most = Some(times);
+        self
+    }
+
+    /// At least `times` times.
+    pub fn at_least(mut self, times: usize) -> Self {
+        self.at_least = times;
+        self
+    }
+
+    /// At most `times` times.
+    pub fn at_most(mut self, times: usize) -> Self {
+        self.at_most = Some(times);
+        self
+    }
+
+    /// Use a lazy quantifier.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Use a greedy quantifier (the default).
+    pub fn greedy(mut self) -> Self {
+        self.lazy = false;
+        self
+    }
+
+    fn quantifier(&self) -> String {
+        match (self.at_least, self.at_most) {
+            (0, None) => "*".to_string(),
+            (1, None) => "+".to_string(),
+            (0, Some(1)) => "?".to_string(),
+            (at_least, None) => format!("{{{},}}", at_least),
+            (at_least, Some(at_most)) if at_least == at_most => format!("{{{}}}", at_least),
+            (at_least, Some(at_most)) => format!("{{{},{}}}", at_least, at_most),
+        }
+    }
+}
+
+impl From<Times> for Dsl {
+    fn from(times: Times) -> Self {
+        let regex = format!(
+            "{}{}{}",
+            times.dsl.non_capturing_group_if_needed(),
+            times.quantifier(),
+            if times.lazy { "?" } else { "" },
+        );
+        Dsl::new(&regex, false).with_group_types_of(std::slice::from_ref(&times.dsl))
+    }
+}
+
+impl Flag {
+    fn symbol(&self) -> char {
+        match self {
+            Flag::CaseInsensitive => 'i',
+            Flag::AllowDot => 's',
+            Flag::EnablesCrlfMode => 'R',
+            Flag::MultiLine => 'm',
+            Flag::SwapGreed => 'U',
+            Flag::Unicode => 'u',
+        }
+    }
+}
+
+impl Apply {
+    /// Set a flag.
+    pub fn set(mut self, flag: Flag) -> Self {
+        self.flags_to_remove.retain(|f| *f != flag);
+        if !self.flags_to_add.contains(&flag) {
+            self.flags_to_add.push(flag);
+        }
+        self
+    }
+
+    /// Unset a flag.
+    pub fn unset(mut self, flag: Flag) -> Self {
+        self.flags_to_add.retain(|f| *f != flag);
+        if !self.flags_to_remove.contains(&flag) {
+            self.flags_to_remove.push(flag);
+        }
+        self
+    }
+
+    /// Apply the flags only on the DSL (otherwise, the flags are applied on the rest of the regular expression).
+    pub fn on(self, dsl: impl Into<Dsl>) -> Dsl {
+        self.to_dsl(Some(dsl.into()))
+    }
+
+    fn to_dsl(&self, dsl: Option<Dsl>) -> Dsl {
+        let flags_to_add: String = self.flags_to_add.iter().map(Flag::symbol).collect();
+        let flags_to_remove: String = if self.flags_to_remove.is_empty() {
+            "".into()
+        } else {
+            format!(
+                "-{}",
+                self.flags_to_remove.iter().map(Flag::symbol).join("")
+            )
+        };
+        let on = dsl
+            .as_ref()
+            .map(|d| format!(":{}", d.as_str()))
+            .unwrap_or_default();
+        let regex = format!("(?{}{}{})", flags_to_add, flags_to_remove, on);
+        Dsl::new(&regex, false).with_group_types_of(dsl.as_slice())
+    }
+}
+
+impl From<Apply> for Dsl {
+    fn from(apply: Apply) -> Self {
+        apply.to_dsl(None)
+    }
+}
+
+pub(crate) fn single_word(word: &str) -> Option<String> {
+    let regex = match word {
+        "any_character" => ".",
+        "digit" => "\\d",
+        "not_digit" => "\\D",
+        "white_space" => "\\s",
+        "not_white_space" => "\\S",
+        "word_character" => "\\w",
+        "not_word_character" => "\\W",
+        "beginning_of_line" => "^",
+        "end_of_line" => "$",
+        "word_boundary" => "\\b",
+        "not_word_boundary" => "\\B",
+        "beginning_of_input" => "\\A",
+        "end_of_input" => "\\z",
+        "any_byte" => "(?s-u:.)",
+        "word_start" => "\\b{start}",
+        "word_end" => "\\b{end}",
+        "word_start_half" => "\\b{start-half}",
+        "word_end_half" => "\\b{end-half}",
+        _ => return ascii_class(word),
+    };
+    Some(regex.to_string())
+}
+
+const ASCII_CLASSES: [&str; 13] = [
+    "alnum", "alpha", "blank", "cntrl", "digit", "graph", "lower", "print", "punct", "space",
+    "upper", "word", "xdigit",
+];
+
+/// An ASCII class, like `ascii_alpha` (i.e. `[[:alpha:]]`) or `not_ascii_digit` (i.e. `[[:^digit:]]`).
+fn ascii_class(word: &str) -> Option<String> {
+    let (prefix, name) = match word.strip_prefix("not_") {
+        Some(name) => ("^", name),
+        None => ("", word),
+    };
+    let name = if name == "ascii" {
+        name
+    } else {
+        let name = name.strip_prefix("ascii_")?;
+        if !ASCII_CLASSES.contains(&name) {
+            return None;
+        }
+        name
+    };
+    Some(format!("[[:{}{}:]]", prefix, name))
+}
+
+#[cfg(feature = "parse")]
+impl Parse for Dsl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitStr) {
+            let str: LitStr = input.parse()?;
+            if str.value().is_empty() {
+                Err(Error::new(str.span(), "Empty string is not supported"))
+            } else {
+                Ok(Dsl::eq(&str.value()))
+            }
+        } else if lookahead.peek(LitChar) {
+            let chr: LitChar = input.parse()?;
+            let str = format!("{}", chr.value());
+            Ok(Dsl::eq(&str))
+        } else if lookahead.peek(Ident) {
+            parse_ident(input)
+        } else if lookahead.peek(Token![#]) || lookahead.peek(Token![~]) {
+            let cls: PredefineClass = input.parse()?;
+            Ok(cls.to_dsl())
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
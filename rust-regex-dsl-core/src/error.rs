@@ -0,0 +1,56 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error in a DSL, for example, an invalid regular expression or an unknown class name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DslError {
+    message: String,
+    location: Option<(usize, usize)>,
+}
+
+impl DslError {
+    pub fn new(message: impl Into<String>) -> Self {
+        DslError {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line (starting from 1) of the error in the parsed DSL, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.location.map(|(line, _)| line)
+    }
+
+    /// The column (starting from 1) of the error in the parsed DSL, if known.
+    pub fn column(&self) -> Option<usize> {
+        self.location.map(|(_, column)| column)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<syn::Error> for DslError {
+    fn from(error: syn::Error) -> Self {
+        let start = error.span().start();
+        DslError {
+            message: error.to_string(),
+            location: Some((start.line, start.column + 1)),
+        }
+    }
+}
+
+impl Display for DslError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some((line, column)) => {
+                write!(f, "{} at line {}, column {}", self.message, line, column)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for DslError {}
@@ -0,0 +1,151 @@
+use proc_macro2::Span;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::{Brace, Bracket, Colon, Comma};
+use syn::{braced, bracketed, Error, Ident, Lit, Result};
+
+use crate::flavor::Flavor;
+
+/// Examples that the regular expression must match or reject (i.e. `examples { matches: [...], rejects: [...] }`).
+#[derive(Debug, Clone)]
+pub(crate) struct Examples {
+    span: Span,
+    matches: Vec<Example>,
+    rejects: Vec<Example>,
+}
+
+#[derive(Debug, Clone)]
+struct Example {
+    span: Span,
+    value: Vec<u8>,
+    bytes: bool,
+}
+
+impl Examples {
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        input.peek2(Brace)
+            && input
+                .fork()
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident == "examples")
+    }
+
+    pub(crate) fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Verify all the examples using `is_match`, return an error for every example that failed.
+    pub(crate) fn check(
+        &self,
+        flavor: Flavor,
+        is_match: impl Fn(&[u8]) -> std::result::Result<bool, String>,
+    ) -> Result<()> {
+        let mut errors: Option<Error> = None;
+        let mut add_error = |error: Error| match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        };
+        for (examples, should_match) in [(&self.matches, true), (&self.rejects, false)] {
+            for example in examples {
+                if example.bytes && flavor != Flavor::Bytes {
+                    add_error(Error::new(
+                        example.span,
+                        "A byte string example is only supported with bytes",
+                    ));
+                    continue;
+                }
+                match is_match(&example.value) {
+                    Ok(matched) if matched == should_match => {}
+                    Ok(true) => add_error(Error::new(
+                        example.span,
+                        "The regular expression should not match this example",
+                    )),
+                    Ok(false) => add_error(Error::new(
+                        example.span,
+                        "The regular expression does not match this example",
+                    )),
+                    Err(e) => add_error(Error::new(
+                        example.span,
+                        format!("Failed to match this example: {}", e),
+                    )),
+                }
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Parse for Examples {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        let content;
+        braced!(content in input);
+        let mut matches = None;
+        let mut rejects = None;
+        while !content.is_empty() {
+            if content.peek(Comma) {
+                let _: Comma = content.parse()?;
+                continue;
+            }
+            let name: Ident = content.parse()?;
+            let target = match name.to_string().as_str() {
+                "matches" => &mut matches,
+                "rejects" => &mut rejects,
+                _ => {
+                    return Err(Error::new(
+                        name.span(),
+                        format!(
+                            "Unknown examples type: {}, expected matches or rejects",
+                            name
+                        ),
+                    ))
+                }
+            };
+            if target.is_some() {
+                return Err(Error::new(name.span(), format!("Duplicate {}", name)));
+            }
+            let _: Colon = content.parse()?;
+            if !content.peek(Bracket) {
+                return Err(content.error("Expected a list of examples (i.e. [\"...\", ...])"));
+            }
+            let list;
+            bracketed!(list in content);
+            let examples: Punctuated<Example, Comma> = Punctuated::parse_terminated(&list)?;
+            *target = Some(examples.into_iter().collect::<Vec<_>>());
+        }
+        let matches = matches.unwrap_or_default();
+        let rejects = rejects.unwrap_or_default();
+        if matches.is_empty() && rejects.is_empty() {
+            return Err(Error::new(ident.span(), "No examples"));
+        }
+        Ok(Examples {
+            span: ident.span(),
+            matches,
+            rejects,
+        })
+    }
+}
+
+impl Parse for Example {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let example: Lit = input.parse()?;
+        let (value, bytes) = match &example {
+            Lit::Str(str) => (str.value().into_bytes(), false),
+            Lit::ByteStr(bytes) => (bytes.value(), true),
+            _ => {
+                return Err(Error::new(
+                    example.span(),
+                    "An example must be a string literal",
+                ))
+            }
+        };
+        Ok(Example {
+            span: example.span(),
+            value,
+            bytes,
+        })
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(feature = "parse")]
+use proc_macro2::{Span, TokenStream};
+#[cfg(feature = "parse")]
+use quote::quote;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flavor {
+    Str,
+    Bytes,
+    #[cfg(feature = "fancy")]
+    Fancy,
+}
+
+impl Flavor {
+    /// Validate a part of a regular expression that might end up in any flavor.
+    pub fn validate_any(regex: &str) -> Option<String> {
+        // The bytes flavor accepts anything the string flavor accepts.
+        let flavors = [
+            Flavor::Bytes,
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy,
+        ];
+        let errors: Vec<_> = flavors
+            .iter()
+            .map(|flavor| flavor.validate(regex))
+            .collect::<Option<_>>()?;
+        errors.into_iter().next()
+    }
+
+    pub fn validate(&self, regex: &str) -> Option<String> {
+        self.capture_names(regex).err()
+    }
+
+    /// Add a hint to an error of a regular expression that uses look-around or backreferences without the fancy
+    /// flavor.
+    #[cfg_attr(not(feature = "fancy"), allow(unused_variables))]
+    pub fn with_fancy_hint(&self, error: String, regex: &str) -> String {
+        #[cfg(feature = "fancy")]
+        if *self != Flavor::Fancy
+            && fancy_regex::Expr::parse_tree(regex).is_ok_and(|tree| uses_fancy(&tree.expr))
+        {
+            return format!(
+                "{}\nLook-around and backreferences need the fancy mode (regex_dsl_fancy!, regex_fancy! or create_capture!(Name, fancy, ...))",
+                error
+            );
+        }
+        error
+    }
+
+    /// Build the regular expression (to validate it) without options.
+    pub fn capture_names(&self, regex: &str) -> Result<Vec<Option<String>>, String> {
+        let names: Vec<Option<String>> = match self {
+            Flavor::Str => regex::Regex::new(regex)
+                .map_err(|e| format!("{}", e))?
+                .capture_names()
+                .map(|name| name.map(str::to_string))
+                .collect(),
+            Flavor::Bytes => regex::bytes::Regex::new(regex)
+                .map_err(|e| format!("{}", e))?
+                .capture_names()
+                .map(|name| name.map(str::to_string))
+                .collect(),
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => fancy_regex::Regex::new(regex)
+                .map_err(|e| format!("{}", e))?
+                .capture_names()
+                .map(|name| name.map(str::to_string))
+                .collect(),
+        };
+        Ok(names)
+    }
+
+    /// The name of the crate that implements the flavor.
+    pub fn crate_name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => "fancy_regex",
+            _ => "regex",
+        }
+    }
+
+    pub fn utf8(&self) -> bool {
+        *self != Flavor::Bytes
+    }
+}
+
+/// The code generation of the macros.
+#[cfg(feature = "parse")]
+impl Flavor {
+    /// The flavor of the `fancy` mode, or an error (at the span of the mode) without the `fancy` feature.
+    #[cfg_attr(feature = "fancy", allow(unused_variables))]
+    pub fn fancy(span: Span) -> syn::Result<Self> {
+        #[cfg(feature = "fancy")]
+        return Ok(Flavor::Fancy);
+        #[cfg(not(feature = "fancy"))]
+        Err(syn::Error::new(
+            span,
+            "The fancy mode requires the fancy feature",
+        ))
+    }
+
+    pub fn regex_type(&self) -> TokenStream {
+        match self {
+            Flavor::Str => quote! { rust_regex_dsl::Regex },
+            Flavor::Bytes => quote! { rust_regex_dsl::bytes::Regex },
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { rust_regex_dsl::fancy::Regex },
+        }
+    }
+
+    pub fn builder_type(&self) -> TokenStream {
+        match self {
+            Flavor::Str => quote! { rust_regex_dsl::RegexBuilder },
+            Flavor::Bytes => quote! { rust_regex_dsl::bytes::RegexBuilder },
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { rust_regex_dsl::fancy::RegexBuilder },
+        }
+    }
+
+    pub fn captures_type(&self) -> TokenStream {
+        match self {
+            Flavor::Str => quote! { rust_regex_dsl::Captures },
+            Flavor::Bytes => quote! { rust_regex_dsl::bytes::Captures },
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { rust_regex_dsl::fancy::Captures },
+        }
+    }
+
+    pub fn haystack_type(&self) -> TokenStream {
+        match self {
+            Flavor::Bytes => quote! { [u8] },
+            _ => quote! { str },
+        }
+    }
+
+    pub fn owned_type(&self) -> TokenStream {
+        match self {
+            Flavor::Bytes => quote! { Vec<u8> },
+            _ => quote! { String },
+        }
+    }
+
+    pub fn as_haystack(&self) -> TokenStream {
+        match self {
+            Flavor::Bytes => quote! { as_bytes },
+            _ => quote! { as_str },
+        }
+    }
+
+    pub fn owned_as_haystack(&self) -> TokenStream {
+        match self {
+            Flavor::Bytes => quote! { as_slice },
+            _ => quote! { as_str },
+        }
+    }
+
+    /// The type that `catch` returns for an item (an `Option`, or a `Result` of an `Option` for fancy_regex, that can
+    /// fail in run time).
+    pub fn catch_type(&self, item: TokenStream) -> TokenStream {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { Result<Option<#item>, rust_regex_dsl::fancy::Error> },
+            _ => quote! { Option<#item> },
+        }
+    }
+
+    /// The type of the items that `catch_all` returns (the item, or a `Result` of it for fancy_regex).
+    pub fn catch_all_type(&self, item: TokenStream) -> TokenStream {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { Result<#item, rust_regex_dsl::fancy::Error> },
+            _ => item,
+        }
+    }
+
+    /// The captures of the first match in `haystack`, mapped with `new` (as a [`Flavor::catch_type`]).
+    pub fn captures(&self, regex: TokenStream, new: TokenStream) -> TokenStream {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => {
+                quote! { #regex.captures(haystack).map(|captures| captures.map(#new)) }
+            }
+            _ => quote! { #regex.captures(haystack).map(#new) },
+        }
+    }
+
+    /// Does the regular expression match `haystack` (as a `bool`). A fancy_regex run time error (like exceeding the
+    /// backtrack limit) panics.
+    pub fn is_match(&self, regex: TokenStream) -> TokenStream {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => quote! { #regex.is_match(haystack).unwrap() },
+            _ => quote! { #regex.is_match(haystack) },
+        }
+    }
+
+    /// The captures of all the matches in `haystack`, mapped with `new` (as an `Iterator` of
+    /// [`Flavor::catch_all_type`]).
+    pub fn captures_iter(&self, regex: TokenStream, new: TokenStream) -> TokenStream {
+        match self {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => {
+                quote! { #regex.captures_iter(haystack).map(|captures| captures.map(#new)) }
+            }
+            _ => quote! { #regex.captures_iter(haystack).map(#new) },
+        }
+    }
+}
+
+#[cfg(feature = "fancy")]
+fn uses_fancy(expr: &fancy_regex::Expr) -> bool {
+    use fancy_regex::Expr;
+    match expr {
+        Expr::LookAround(..)
+        | Expr::Backref { .. }
+        | Expr::BackrefWithRelativeRecursionLevel { .. } => true,
+        _ => expr.children_iter().any(uses_fancy),
+    }
+}
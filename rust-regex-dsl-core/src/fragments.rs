@@ -0,0 +1,25 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+
+thread_local! {
+    static FRAGMENTS: RefCell<HashMap<String, TokenStream>> = RefCell::new(HashMap::new());
+}
+
+/// Make the fragments available to `use_fragment` while `f` is running.
+pub fn with_fragments<T>(fragments: HashMap<String, TokenStream>, f: impl FnOnce() -> T) -> T {
+    let previous = FRAGMENTS.replace(fragments);
+    let result = f();
+    FRAGMENTS.set(previous);
+    result
+}
+
+/// Take a fragment while it is being parsed, so a fragment can not use itself.
+pub fn take_fragment(name: &str) -> Option<TokenStream> {
+    FRAGMENTS.with_borrow_mut(|fragments| fragments.remove(name))
+}
+
+pub fn return_fragment(name: &str, fragment: TokenStream) {
+    FRAGMENTS.with_borrow_mut(|fragments| fragments.insert(name.to_string(), fragment));
+}
@@ -1,5 +1,4 @@
 use crate::dsl::Dsl;
-use itertools::Itertools;
 use syn::{parse::ParseBuffer, Result};
 
 use super::parse_list::parse_list_to_vec;
@@ -9,11 +8,5 @@ pub fn parse_any(group: &ParseBuffer) -> Result<Dsl> {
     if items.len() < 2 {
         return Err(group.error("Concat must have at least two elements".to_string()));
     }
-    let regex = items
-        .iter()
-        .map(Dsl::non_capturing_group_if_needed)
-        .join("|");
-
-    let dsl = Dsl::new(&regex, true);
-    Ok(dsl)
+    Ok(Dsl::any(&items))
 }
@@ -1,7 +1,4 @@
-use std::collections::HashSet;
-
-use crate::dsl::Dsl;
-use itertools::Itertools;
+use crate::dsl::{Dsl, Flag};
 use syn::parse::Parse;
 use syn::parse::ParseStream;
 use syn::token::Minus;
@@ -11,32 +8,21 @@ use syn::Result;
 use super::parse_ident::parse_ident;
 use super::parse_list::parse_list;
 
-enum Flag {
-    CaseInsensitive,
-    MultiLine,
-    AllowDot,
-    EnablesCrlfMode,
-}
-impl Flag {
-    fn symbol(&self) -> char {
-        match self {
-            Flag::CaseInsensitive => 'i',
-            Flag::AllowDot => 's',
-            Flag::EnablesCrlfMode => 'R',
-            Flag::MultiLine => 'm',
-        }
-    }
-}
-impl Parse for Flag {
+struct ParsedFlag(Flag);
+impl Parse for ParsedFlag {
     fn parse(input: ParseStream) -> Result<Self> {
         if parse_ident(input, "case_insensitive")? {
-            Ok(Flag::CaseInsensitive)
+            Ok(ParsedFlag(Flag::CaseInsensitive))
         } else if parse_ident(input, "multi_line")? {
-            Ok(Flag::MultiLine)
+            Ok(ParsedFlag(Flag::MultiLine))
         } else if parse_ident(input, "allow_dot")? {
-            Ok(Flag::AllowDot)
+            Ok(ParsedFlag(Flag::AllowDot))
         } else if parse_ident(input, "enables_crlf_mode")? {
-            Ok(Flag::EnablesCrlfMode)
+            Ok(ParsedFlag(Flag::EnablesCrlfMode))
+        } else if parse_ident(input, "swap_greed")? {
+            Ok(ParsedFlag(Flag::SwapGreed))
+        } else if parse_ident(input, "unicode")? {
+            Ok(ParsedFlag(Flag::Unicode))
         } else {
             Err(input.error("Unknown flag"))
         }
@@ -52,43 +38,41 @@ impl Parse for Element {
         let lookahead = input.lookahead1();
         if lookahead.peek(Plus) {
             let _: Plus = input.parse()?;
-            let flag: Flag = input.parse()?;
-            Ok(Element::FlagToAdd(flag))
+            let flag: ParsedFlag = input.parse()?;
+            Ok(Element::FlagToAdd(flag.0))
         } else if lookahead.peek(Minus) {
             let _: Minus = input.parse()?;
-            let flag: Flag = input.parse()?;
-            Ok(Element::FlagToRemove(flag))
+            let flag: ParsedFlag = input.parse()?;
+            Ok(Element::FlagToRemove(flag.0))
         } else {
             Ok(Element::Dsl(input.parse()?))
         }
     }
 }
-struct Apply {
-    flags_to_add: HashSet<char>,
-    flags_to_remove: HashSet<char>,
+struct ParsedApply {
+    flags_to_add: Vec<Flag>,
+    flags_to_remove: Vec<Flag>,
     dsl: Option<Dsl>,
 }
-impl Apply {
-    fn to_dsl(&self) -> Dsl {
-        let flags_to_add: String = self.flags_to_add.iter().join("");
-        let flags_to_remove: String = if self.flags_to_remove.is_empty() {
-            "".into()
-        } else {
-            format!("-{}", self.flags_to_remove.iter().join(""))
-        };
-        let dsl = self
-            .dsl
-            .as_ref()
-            .map(|d| format!(":{}", d.regex()))
-            .unwrap_or_default();
-        let regex = format!("(?{}{}{})", flags_to_add, flags_to_remove, dsl);
-        Dsl::new(&regex, false)
+impl ParsedApply {
+    fn into_dsl(self) -> Dsl {
+        let mut apply = Dsl::apply();
+        for flag in self.flags_to_add {
+            apply = apply.set(flag);
+        }
+        for flag in self.flags_to_remove {
+            apply = apply.unset(flag);
+        }
+        match self.dsl {
+            Some(dsl) => apply.on(dsl),
+            None => apply.into(),
+        }
     }
 }
-impl Parse for Apply {
+impl Parse for ParsedApply {
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut flags_to_add = HashSet::new();
-        let mut flags_to_remove = HashSet::new();
+        let mut flags_to_add = vec![];
+        let mut flags_to_remove = vec![];
         let mut dsl = None;
         parse_list(input, |element| {
             match element {
@@ -99,18 +83,16 @@ impl Parse for Apply {
                     dsl = Some(regex);
                 }
                 Element::FlagToAdd(flag) => {
-                    let flag = flag.symbol();
                     if flags_to_remove.contains(&flag) {
                         return Err(input.error("Can not add and remove the same flag"));
                     }
-                    flags_to_add.insert(flag);
+                    flags_to_add.push(flag);
                 }
                 Element::FlagToRemove(flag) => {
-                    let flag = flag.symbol();
                     if flags_to_add.contains(&flag) {
                         return Err(input.error("Can not add and remove the same flag"));
                     }
-                    flags_to_remove.insert(flag);
+                    flags_to_remove.push(flag);
                 }
             }
             Ok(())
@@ -118,7 +100,7 @@ impl Parse for Apply {
         if flags_to_add.is_empty() && flags_to_remove.is_empty() {
             return Err(input.error("No flags to apply"));
         }
-        Ok(Apply {
+        Ok(ParsedApply {
             flags_to_add,
             flags_to_remove,
             dsl,
@@ -126,7 +108,7 @@ impl Parse for Apply {
     }
 }
 pub fn parse_apply(group: ParseStream) -> Result<Dsl> {
-    let apply: Apply = group.parse()?;
+    let apply: ParsedApply = group.parse()?;
 
-    Ok(apply.to_dsl())
+    Ok(apply.into_dsl())
 }
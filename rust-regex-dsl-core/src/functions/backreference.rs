@@ -0,0 +1,14 @@
+use syn::{parse::ParseBuffer, Ident, Result};
+
+use crate::dsl::Dsl;
+
+#[cfg(feature = "fancy")]
+pub fn parse_backreference(_ident: &Ident, group: &ParseBuffer) -> Result<Dsl> {
+    let name: Ident = group.parse()?;
+    Ok(Dsl::backreference(&name.to_string()))
+}
+
+#[cfg(not(feature = "fancy"))]
+pub fn parse_backreference(ident: &Ident, _group: &ParseBuffer) -> Result<Dsl> {
+    Err(super::lookaround::requires_fancy(ident))
+}
@@ -0,0 +1,11 @@
+use syn::{parse::ParseBuffer, Error, LitInt, Result};
+
+use crate::dsl::Dsl;
+
+pub fn parse_byte(group: &ParseBuffer) -> Result<Dsl> {
+    let number: LitInt = group.parse()?;
+    let Ok(byte) = number.base10_parse::<u8>() else {
+        return Err(Error::new(number.span(), "Invalid byte"));
+    };
+    Ok(Dsl::byte(byte))
+}
@@ -0,0 +1,46 @@
+use quote::ToTokens;
+use syn::{parse::ParseBuffer, token::Colon, Error, Ident, Result, Token, Type};
+
+use crate::dsl::Dsl;
+
+use super::{
+    parse_ident::parse_ident,
+    parse_list::{parse_list_to_vec, skip_comma},
+};
+
+pub fn parse_capture_group(group: &ParseBuffer) -> Result<Dsl> {
+    let name = if parse_ident(group, "name")? {
+        let _: Colon = group.parse()?;
+        let name: Ident = group.parse()?;
+        skip_comma(group)?;
+        Some(name)
+    } else {
+        None
+    };
+    let group_type = if group.peek(Token![type]) {
+        let type_token: Token![type] = group.parse()?;
+        if name.is_none() {
+            return Err(Error::new(
+                type_token.span,
+                "Only a named group can have a type",
+            ));
+        }
+        let _: Colon = group.parse()?;
+        let group_type: Type = group.parse()?;
+        skip_comma(group)?;
+        Some(group_type)
+    } else {
+        None
+    };
+    let items: Vec<Dsl> = parse_list_to_vec(group)?;
+    if items.is_empty() {
+        return Err(group.error("Nothing to capture in the group".to_string()));
+    }
+    let dsl = Dsl::concat(&items);
+    match (name, group_type) {
+        (Some(name), Some(group_type)) => Ok(Dsl::named_group(&name.to_string(), dsl)
+            .with_group_type(&name.to_string(), group_type.to_token_stream())),
+        (Some(name), None) => Ok(Dsl::named_group(&name.to_string(), dsl)),
+        (None, _) => Ok(Dsl::group(dsl)),
+    }
+}
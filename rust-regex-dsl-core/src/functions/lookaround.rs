@@ -0,0 +1,60 @@
+use syn::{parse::ParseBuffer, Error, Ident, Result};
+
+#[cfg(feature = "fancy")]
+use super::parse_list::parse_list_to_vec;
+use crate::dsl::Dsl;
+
+pub fn unsupported_lookaround(ident: &Ident) -> Error {
+    let (message, replacement) = match ident.to_string().as_str() {
+        "followed_by" => ("Look-ahead (followed_by) is not supported by the regex crate. Add the following text to the expression and capture the part you need instead, for example: concat { group { name: value, ... }, <following text> }", "lookahead"),
+        "not_followed_by" => ("Negative look-ahead (not_followed_by) is not supported by the regex crate. Use a not_any_of class for the next character, or check the text after the match in code instead", "negative_lookahead"),
+        "preceded_by" => ("Look-behind (preceded_by) is not supported by the regex crate. Add the preceding text to the expression and capture the part you need instead, for example: concat { <preceding text>, group { name: value, ... } }", "lookbehind"),
+        _ => ("Negative look-behind (not_preceded_by) is not supported by the regex crate. Use a not_any_of class for the previous character, or check the text before the match in code instead", "negative_lookbehind"),
+    };
+    let alternative = if cfg!(feature = "fancy") {
+        format!("use {} with regex_dsl_fancy!", replacement)
+    } else {
+        format!(
+            "enable the fancy feature and use {} with regex_dsl_fancy!",
+            replacement
+        )
+    };
+    Error::new(
+        ident.span(),
+        format!("{}. Alternatively, {}", message, alternative),
+    )
+}
+
+#[cfg(not(feature = "fancy"))]
+pub fn requires_fancy(ident: &Ident) -> Error {
+    Error::new(
+        ident.span(),
+        format!(
+            "{} is not supported by the regex crate, enable the fancy feature and use regex_dsl_fancy! to use it",
+            ident
+        ),
+    )
+}
+
+#[cfg(feature = "fancy")]
+pub fn parse_lookaround(ident: &Ident, group: &ParseBuffer) -> Result<Dsl> {
+    let items: Vec<Dsl> = parse_list_to_vec(group)?;
+    if items.is_empty() {
+        return Err(Error::new(
+            ident.span(),
+            format!("Nothing to look for in {}", ident),
+        ));
+    }
+    let dsl = Dsl::concat(&items);
+    match ident.to_string().as_str() {
+        "lookahead" => Ok(Dsl::lookahead(dsl)),
+        "negative_lookahead" => Ok(Dsl::negative_lookahead(dsl)),
+        "lookbehind" => Ok(Dsl::lookbehind(dsl)),
+        _ => Ok(Dsl::negative_lookbehind(dsl)),
+    }
+}
+
+#[cfg(not(feature = "fancy"))]
+pub fn parse_lookaround(ident: &Ident, _group: &ParseBuffer) -> Result<Dsl> {
+    Err(requires_fancy(ident))
+}
@@ -1,12 +1,16 @@
 pub mod any;
 pub mod any_of;
 pub mod apply;
+pub mod backreference;
+pub mod byte;
 pub mod capture_group;
 pub mod concat;
 pub mod eq;
-mod parse_ident;
+pub mod lookaround;
+pub mod parse_ident;
 pub mod parse_list;
 mod quantifier_type;
 pub mod regex;
 pub mod repeat;
 pub mod times;
+pub mod use_fragment;
@@ -0,0 +1,59 @@
+use syn::parse::{Parse, ParseStream};
+use syn::token::Comma;
+use syn::{Result, Token};
+
+use crate::options::RegexOptions;
+
+pub fn parse_list<T: Parse>(
+    input: ParseStream,
+    mut added: impl FnMut(T) -> Result<()>,
+) -> Result<()> {
+    while !input.is_empty() {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Comma) {
+            let _: Comma = input.parse()?;
+        } else {
+            let t: T = input.parse()?;
+            added(t)?;
+        }
+    }
+    Ok(())
+}
+pub fn parse_list_to_vec<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
+    let mut ret = vec![];
+    parse_list(input, |t| {
+        ret.push(t);
+        Ok(())
+    })?;
+    Ok(ret)
+}
+/// Parse a list that might be followed by regular expression options (`name = value`).
+pub fn parse_list_to_vec_with_options<T: Parse>(
+    input: ParseStream,
+) -> Result<(Vec<T>, RegexOptions)> {
+    let mut ret = vec![];
+    while !input.is_empty() && !RegexOptions::peek(input) {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Comma) {
+            let _: Comma = input.parse()?;
+        } else {
+            ret.push(input.parse()?);
+        }
+    }
+    Ok((ret, input.parse()?))
+}
+pub fn skip_comma(input: ParseStream) -> Result<()> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(Comma) {
+        let _: Comma = input.parse()?;
+    }
+    Ok(())
+}
+pub fn parse_static(input: ParseStream) -> Result<bool> {
+    if input.peek(Token![static]) {
+        let _: Token![static] = input.parse()?;
+        skip_comma(input)?;
+        return Ok(true);
+    }
+    Ok(false)
+}
@@ -1,6 +1,8 @@
 use syn::parse::ParseStream;
 use syn::Result;
 
+use crate::dsl::Times;
+
 use super::parse_ident::parse_ident;
 
 #[derive(Default)]
@@ -20,10 +22,10 @@ impl QuantifierType {
         }
     }
 
-    pub fn postfix(&self) -> &str {
+    pub fn apply(&self, times: Times) -> Times {
         match self {
-            QuantifierType::Greedy => "",
-            QuantifierType::Lazy => "?",
+            QuantifierType::Greedy => times.greedy(),
+            QuantifierType::Lazy => times.lazy(),
         }
     }
 }
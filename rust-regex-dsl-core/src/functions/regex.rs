@@ -0,0 +1,8 @@
+use syn::{parse::ParseBuffer, Error, LitStr, Result};
+
+use crate::dsl::Dsl;
+
+pub fn parse_regex(group: &ParseBuffer) -> Result<Dsl> {
+    let str: LitStr = group.parse()?;
+    Dsl::regex(&str.value()).map_err(|e| Error::new(str.span(), e.message()))
+}
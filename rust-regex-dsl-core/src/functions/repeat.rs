@@ -1,7 +1,7 @@
 use syn::parse::{Parse, ParseBuffer, ParseStream};
 use syn::Result;
 
-use crate::dsl::Dsl;
+use crate::dsl::{Dsl, Times};
 
 use super::parse_list::parse_list;
 use super::quantifier_type::QuantifierType;
@@ -24,14 +24,8 @@ struct Repeat {
     dsl: Dsl,
 }
 impl Repeat {
-    fn to_dsl(&self, operator: &str) -> Dsl {
-        let regex = format!(
-            "{}{}{}",
-            self.dsl.non_capturing_group_if_needed(),
-            operator,
-            self.quantifier_type.postfix(),
-        );
-        Dsl::new(&regex, false)
+    fn into_dsl(self, times: fn(Dsl) -> Times) -> Dsl {
+        self.quantifier_type.apply(times(self.dsl)).into()
     }
 }
 impl Parse for Repeat {
@@ -65,8 +59,8 @@ impl Parse for Repeat {
         })
     }
 }
-pub fn parse_repeat(group: &ParseBuffer, operator: &str) -> Result<Dsl> {
+pub fn parse_repeat(group: &ParseBuffer, times: fn(Dsl) -> Times) -> Result<Dsl> {
     let repeat: Repeat = group.parse()?;
 
-    Ok(repeat.to_dsl(operator))
+    Ok(repeat.into_dsl(times))
 }
@@ -4,7 +4,10 @@ use syn::{
     Error, LitInt, Result,
 };
 
-use crate::{dsl::Dsl, functions::parse_list::parse_list};
+use crate::{
+    dsl::{Dsl, Times},
+    functions::parse_list::parse_list,
+};
 
 use super::{parse_ident::parse_ident, quantifier_type::QuantifierType};
 enum Element {
@@ -37,23 +40,16 @@ impl Parse for Element {
         }
     }
 }
-struct Times {
+struct ParsedTimes {
     quantifier_type: QuantifierType,
-    times: String,
-    dsl: Dsl,
+    times: Times,
 }
-impl Times {
-    fn to_dsl(&self) -> Dsl {
-        let regex = format!(
-            "{}{}{}",
-            self.dsl.non_capturing_group_if_needed(),
-            self.times,
-            self.quantifier_type.postfix(),
-        );
-        Dsl::new(&regex, false)
+impl ParsedTimes {
+    fn into_dsl(self) -> Dsl {
+        self.quantifier_type.apply(self.times).into()
     }
 }
-impl Parse for Times {
+impl Parse for ParsedTimes {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut quantifier_type = None;
         let mut at_least = None;
@@ -101,6 +97,7 @@ impl Parse for Times {
         let Some(dsl) = dsl else {
             return Err(input.error("Nothing to repeat"));
         };
+        let times = Dsl::times(dsl);
         let times = if let Some(exactly) = exactly {
             if at_least.is_some() {
                 return Err(input.error("Can not set exactly and at least together"));
@@ -108,15 +105,15 @@ impl Parse for Times {
             if at_most.is_some() {
                 return Err(input.error("Can not set exactly and at most together"));
             }
-            format!("{{{}}}", exactly)
+            times.exactly(exactly)
         } else if let Some(at_most) = at_most {
             if let Some(at_least) = at_least {
-                format!("{{{},{}}}", at_least, at_most)
+                times.at_least(at_least).at_most(at_most)
             } else {
                 return Err(input.error("At most without at least is not supported"));
             }
         } else if let Some(at_last) = at_least {
-            format!("{{{},}}", at_last)
+            times.at_least(at_last)
         } else {
             return Err(input.error("Must set either exactly, at_most and at_least or at_least"));
         };
@@ -124,11 +121,10 @@ impl Parse for Times {
         Ok(Self {
             quantifier_type,
             times,
-            dsl,
         })
     }
 }
 pub fn parse_times(group: &ParseBuffer) -> Result<Dsl> {
-    let times: Times = group.parse()?;
-    Ok(times.to_dsl())
+    let times: ParsedTimes = group.parse()?;
+    Ok(times.into_dsl())
 }
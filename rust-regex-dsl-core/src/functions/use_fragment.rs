@@ -0,0 +1,23 @@
+use syn::{parse::ParseBuffer, Error, Ident, Result};
+
+use crate::{
+    dsl::Dsl,
+    fragments::{return_fragment, take_fragment},
+};
+
+use super::parse_list::parse_list_to_vec;
+
+pub fn parse_use_fragment(group: &ParseBuffer) -> Result<Dsl> {
+    let name: Ident = group.parse()?;
+    let name_str = name.to_string();
+    let Some(fragment) = take_fragment(&name_str) else {
+        return Err(Error::new(
+            name.span(),
+            format!("Unknown fragment: {}", name_str),
+        ));
+    };
+    let items: Result<Vec<Dsl>> = syn::parse::Parser::parse2(parse_list_to_vec, fragment.clone());
+    return_fragment(&name_str, fragment);
+    let items = items?;
+    Ok(Dsl::concat(&items))
+}
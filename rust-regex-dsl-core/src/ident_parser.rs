@@ -0,0 +1,66 @@
+use syn::{
+    parse::{ParseBuffer, ParseStream},
+    Error, Ident, Result,
+};
+
+use crate::{
+    dsl::Dsl,
+    functions::{
+        any::parse_any,
+        any_of::parse_any_of,
+        apply::parse_apply,
+        backreference::parse_backreference,
+        byte::parse_byte,
+        capture_group::parse_capture_group,
+        concat::parse_concat,
+        eq::parse_eq,
+        lookaround::{parse_lookaround, unsupported_lookaround},
+        regex::parse_regex,
+        repeat::parse_repeat,
+        times::parse_times,
+        use_fragment::parse_use_fragment,
+    },
+    group::parse_group,
+};
+
+pub fn parse_ident(input: ParseStream) -> Result<Dsl> {
+    let ident: Ident = input.parse()?;
+    let group = parse_group(input)?;
+    match group {
+        Some(group) => parse_function(ident, &group),
+        None => parse_single_word(ident),
+    }
+}
+
+fn parse_function(ident: Ident, group: &ParseBuffer) -> Result<Dsl> {
+    match ident.to_string().as_str() {
+        "regex" => parse_regex(group),
+        "eq" => parse_eq(group),
+        "concat" => parse_concat(group),
+        "any" => parse_any(group),
+        "any_of" => parse_any_of(group, true),
+        "not_any_of" => parse_any_of(group, false),
+        "group" => parse_capture_group(group),
+        "times" => parse_times(group),
+        "repeat" => parse_repeat(group, Dsl::repeat),
+        "maybe_repeat" => parse_repeat(group, Dsl::maybe_repeat),
+        "maybe" => parse_repeat(group, Dsl::maybe),
+        "apply" => parse_apply(group),
+        "byte" => parse_byte(group),
+        "use_fragment" => parse_use_fragment(group),
+        "backreference" => parse_backreference(&ident, group),
+        "lookahead" | "negative_lookahead" | "lookbehind" | "negative_lookbehind" => {
+            parse_lookaround(&ident, group)
+        }
+        "followed_by" | "not_followed_by" | "preceded_by" | "not_preceded_by" => {
+            Err(unsupported_lookaround(&ident))
+        }
+        unknown_function => Err(Error::new(
+            ident.span(),
+            format!("Unknown function: {}", unknown_function),
+        )),
+    }
+}
+pub fn parse_single_word(ident: Ident) -> Result<Dsl> {
+    Dsl::word(&ident.to_string()).map_err(|e| Error::new(ident.span(), e.message()))
+}
@@ -0,0 +1,44 @@
+//! The core of the [rust-regex-dsl](https://github.com/yift/rust-regex-dsl/) package.
+//!
+//! This crate has the implementation of the DSL that is used by the macros, and a runtime builder for it (see [`Dsl`]), that can be used
+//! to create regular expressions that are not known in compile time, and a runtime parser for the DSL text (see `parse_dsl`).
+//!
+//! Optional features:
+//! * `parse` - The DSL parser (`parse_dsl` and `Dsl::parse`) and the code generation of the macros. Without it, the crate
+//!   only depends on `regex`.
+//! * `fancy` - Support for [fancy_regex](https://crates.io/crates/fancy-regex) (look-around and backreferences).
+
+mod class;
+mod dsl;
+mod error;
+#[cfg(feature = "parse")]
+mod examples;
+#[doc(hidden)]
+pub mod flavor;
+#[cfg(feature = "parse")]
+#[doc(hidden)]
+pub mod fragments;
+#[cfg(feature = "parse")]
+#[doc(hidden)]
+pub mod functions;
+#[cfg(feature = "parse")]
+mod group;
+#[cfg(feature = "parse")]
+mod ident_parser;
+#[cfg(feature = "parse")]
+#[doc(hidden)]
+pub mod options;
+#[cfg(feature = "parse")]
+mod parse;
+mod predefined_class;
+#[cfg(feature = "parse")]
+mod user_class;
+
+pub use class::Class;
+#[cfg(feature = "parse")]
+#[doc(hidden)]
+pub use dsl::GroupType;
+pub use dsl::{Apply, Dsl, Flag, Times};
+pub use error::DslError;
+#[cfg(feature = "parse")]
+pub use parse::parse_dsl;
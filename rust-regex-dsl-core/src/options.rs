@@ -0,0 +1,390 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::token::Comma;
+use syn::{Error, Ident, Lit, Result, Token};
+
+use crate::examples::Examples;
+use crate::flavor::Flavor;
+
+/// The options of a regular expression builder (`name = value`, after the regular expression in the macros).
+#[derive(Debug, Clone, Default)]
+pub struct RegexOptions {
+    options: Vec<RegexOption>,
+    examples: Option<Examples>,
+}
+
+enum BuiltRegex {
+    Str(regex::Regex),
+    Bytes(regex::bytes::Regex),
+    #[cfg(feature = "fancy")]
+    Fancy(fancy_regex::Regex),
+}
+
+#[derive(Debug, Clone)]
+struct RegexOption {
+    name: Ident,
+    value: OptionValue,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OptionValue {
+    Bool(bool),
+    Number(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OptionKind {
+    Bool,
+    Number(usize),
+}
+
+fn option_kind(name: &str) -> Option<OptionKind> {
+    let kind = match name {
+        "unicode"
+        | "case_insensitive"
+        | "multi_line"
+        | "dot_matches_new_line"
+        | "crlf"
+        | "swap_greed"
+        | "ignore_whitespace"
+        | "octal" => OptionKind::Bool,
+        "size_limit"
+        | "dfa_size_limit"
+        | "backtrack_limit"
+        | "delegate_size_limit"
+        | "delegate_dfa_size_limit" => OptionKind::Number(usize::MAX),
+        "nest_limit" => OptionKind::Number(u32::MAX as usize),
+        "line_terminator" => OptionKind::Number(u8::MAX as usize),
+        _ => return None,
+    };
+    Some(kind)
+}
+
+impl RegexOptions {
+    /// Is the next token the beginning of an option (i.e. `name =` or `examples { ... }`).
+    pub fn peek(input: ParseStream) -> bool {
+        (input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]))
+            || Examples::peek(input)
+    }
+
+    /// Verify that all the options are supported by the flavor.
+    pub fn check(&self, flavor: Flavor) -> Result<()> {
+        for option in &self.options {
+            if option.method(flavor).is_none() {
+                return Err(Error::new(
+                    option.name.span(),
+                    format!(
+                        "The option {} is not supported by {}",
+                        option.name,
+                        flavor.crate_name()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the regular expression (to validate it) with the options.
+    pub fn capture_names(
+        &self,
+        flavor: Flavor,
+        regex: &str,
+    ) -> std::result::Result<Vec<Option<String>>, String> {
+        Ok(self.build(flavor, regex)?.capture_names())
+    }
+
+    /// Verify that the regular expression matches (and rejects) the examples.
+    pub fn check_examples(&self, flavor: Flavor, regex: &str) -> Result<()> {
+        let Some(examples) = &self.examples else {
+            return Ok(());
+        };
+        let regex = self
+            .build(flavor, regex)
+            .map_err(|e| Error::new(examples.span(), e))?;
+        examples.check(flavor, |example| regex.is_match(example))
+    }
+
+    fn build(&self, flavor: Flavor, regex: &str) -> std::result::Result<BuiltRegex, String> {
+        macro_rules! build {
+            ($builder: ty) => {{
+                let mut builder = <$builder>::new(regex);
+                for option in &self.options {
+                    match (option.name.to_string().as_str(), option.value) {
+                        ("unicode", OptionValue::Bool(yes)) => builder.unicode(yes),
+                        ("case_insensitive", OptionValue::Bool(yes)) => {
+                            builder.case_insensitive(yes)
+                        }
+                        ("multi_line", OptionValue::Bool(yes)) => builder.multi_line(yes),
+                        ("dot_matches_new_line", OptionValue::Bool(yes)) => {
+                            builder.dot_matches_new_line(yes)
+                        }
+                        ("crlf", OptionValue::Bool(yes)) => builder.crlf(yes),
+                        ("swap_greed", OptionValue::Bool(yes)) => builder.swap_greed(yes),
+                        ("ignore_whitespace", OptionValue::Bool(yes)) => {
+                            builder.ignore_whitespace(yes)
+                        }
+                        ("octal", OptionValue::Bool(yes)) => builder.octal(yes),
+                        ("size_limit", OptionValue::Number(limit)) => builder.size_limit(limit),
+                        ("dfa_size_limit", OptionValue::Number(limit)) => {
+                            builder.dfa_size_limit(limit)
+                        }
+                        ("nest_limit", OptionValue::Number(limit)) => {
+                            builder.nest_limit(limit as u32)
+                        }
+                        ("line_terminator", OptionValue::Number(byte)) => {
+                            builder.line_terminator(byte as u8)
+                        }
+                        _ => &mut builder,
+                    };
+                }
+                builder.build().map_err(|e| format!("{}", e))?
+            }};
+        }
+        let regex = match flavor {
+            Flavor::Str => BuiltRegex::Str(build!(regex::RegexBuilder)),
+            Flavor::Bytes => BuiltRegex::Bytes(build!(regex::bytes::RegexBuilder)),
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => {
+                let mut builder = fancy_regex::RegexBuilder::new(regex);
+                for option in &self.options {
+                    match (option.name.to_string().as_str(), option.value) {
+                        ("unicode", OptionValue::Bool(yes)) => builder.unicode_mode(yes),
+                        ("case_insensitive", OptionValue::Bool(yes)) => {
+                            builder.case_insensitive(yes)
+                        }
+                        ("multi_line", OptionValue::Bool(yes)) => builder.multi_line(yes),
+                        ("dot_matches_new_line", OptionValue::Bool(yes)) => {
+                            builder.dot_matches_new_line(yes)
+                        }
+                        ("crlf", OptionValue::Bool(yes)) => builder.crlf(yes),
+                        ("ignore_whitespace", OptionValue::Bool(yes)) => {
+                            builder.ignore_whitespace(yes)
+                        }
+                        ("backtrack_limit", OptionValue::Number(limit)) => {
+                            builder.backtrack_limit(limit)
+                        }
+                        ("delegate_size_limit", OptionValue::Number(limit)) => {
+                            builder.delegate_size_limit(limit)
+                        }
+                        ("delegate_dfa_size_limit", OptionValue::Number(limit)) => {
+                            builder.delegate_dfa_size_limit(limit)
+                        }
+                        _ => &mut builder,
+                    };
+                }
+                BuiltRegex::Fancy(builder.build().map_err(|e| format!("{}", e))?)
+            }
+        };
+        Ok(regex)
+    }
+
+    /// The value of a boolean option (i.e. `case_insensitive`), if it was set.
+    pub fn bool_value(&self, name: &str) -> Option<bool> {
+        self.options
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| match option.value {
+                OptionValue::Bool(yes) => Some(yes),
+                OptionValue::Number(_) => None,
+            })
+    }
+
+    /// The value of a numeric option (i.e. `nest_limit`), if it was set.
+    pub fn number_value(&self, name: &str) -> Option<usize> {
+        self.options
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| match option.value {
+                OptionValue::Number(number) => Some(number),
+                OptionValue::Bool(_) => None,
+            })
+    }
+
+    /// The options as inline flags (i.e. `(?i-u)`) to prepend to the regular expression, or `None` if some options
+    /// can not be expressed as flags (like `octal` or `line_terminator`).
+    pub fn inline_flags(&self) -> Option<String> {
+        let mut on = String::new();
+        let mut off = String::new();
+        for option in &self.options {
+            let flag = match (option.name.to_string().as_str(), option.value) {
+                ("unicode", OptionValue::Bool(yes)) => ('u', yes),
+                ("case_insensitive", OptionValue::Bool(yes)) => ('i', yes),
+                ("multi_line", OptionValue::Bool(yes)) => ('m', yes),
+                ("dot_matches_new_line", OptionValue::Bool(yes)) => ('s', yes),
+                ("crlf", OptionValue::Bool(yes)) => ('R', yes),
+                ("swap_greed", OptionValue::Bool(yes)) => ('U', yes),
+                ("ignore_whitespace", OptionValue::Bool(yes)) => ('x', yes),
+                ("octal", OptionValue::Bool(false)) => continue,
+                ("octal", _) | ("line_terminator", _) => return None,
+                // The limits do not change what the regular expression matches.
+                _ => continue,
+            };
+            match flag {
+                (flag, true) => on.push(flag),
+                (flag, false) => off.push(flag),
+            }
+        }
+        let flags = match (on.is_empty(), off.is_empty()) {
+            (true, true) => String::new(),
+            (_, true) => format!("(?{})", on),
+            _ => format!("(?{}-{})", on, off),
+        };
+        Some(flags)
+    }
+
+    /// The code that creates the regular expression with the options.
+    pub fn new_regex(&self, flavor: Flavor, regex: &str) -> TokenStream {
+        let lit = Literal::string(regex);
+        if self.options.is_empty() {
+            let regex_type = flavor.regex_type();
+            return quote! { #regex_type::new(#lit).unwrap() };
+        }
+        let builder_type = flavor.builder_type();
+        let options = self.options.iter().filter_map(|option| {
+            let method = format_ident!("{}", option.method(flavor)?);
+            let value = match option.value {
+                OptionValue::Bool(yes) => quote! { #yes },
+                OptionValue::Number(number) => {
+                    let number = Literal::usize_unsuffixed(number);
+                    quote! { #number }
+                }
+            };
+            Some(quote! { .#method(#value) })
+        });
+        quote! { #builder_type::new(#lit)#(#options)*.build().unwrap() }
+    }
+}
+
+impl BuiltRegex {
+    fn capture_names(&self) -> Vec<Option<String>> {
+        let names: Vec<Option<&str>> = match self {
+            BuiltRegex::Str(regex) => regex.capture_names().collect(),
+            BuiltRegex::Bytes(regex) => regex.capture_names().collect(),
+            #[cfg(feature = "fancy")]
+            BuiltRegex::Fancy(regex) => regex.capture_names().collect(),
+        };
+        names
+            .into_iter()
+            .map(|name| name.map(str::to_string))
+            .collect()
+    }
+
+    fn is_match(&self, example: &[u8]) -> std::result::Result<bool, String> {
+        match self {
+            BuiltRegex::Str(regex) => Ok(regex.is_match(&String::from_utf8_lossy(example))),
+            BuiltRegex::Bytes(regex) => Ok(regex.is_match(example)),
+            #[cfg(feature = "fancy")]
+            BuiltRegex::Fancy(regex) => regex
+                .is_match(&String::from_utf8_lossy(example))
+                .map_err(|e| format!("{}", e)),
+        }
+    }
+}
+
+impl RegexOption {
+    fn method(&self, flavor: Flavor) -> Option<&'static str> {
+        let name = self.name.to_string();
+        let method = match flavor {
+            #[cfg(feature = "fancy")]
+            Flavor::Fancy => match name.as_str() {
+                "unicode" => "unicode_mode",
+                "case_insensitive" => "case_insensitive",
+                "multi_line" => "multi_line",
+                "dot_matches_new_line" => "dot_matches_new_line",
+                "crlf" => "crlf",
+                "ignore_whitespace" => "ignore_whitespace",
+                "backtrack_limit" => "backtrack_limit",
+                "delegate_size_limit" => "delegate_size_limit",
+                "delegate_dfa_size_limit" => "delegate_dfa_size_limit",
+                _ => return None,
+            },
+            _ => match name.as_str() {
+                "unicode" => "unicode",
+                "case_insensitive" => "case_insensitive",
+                "multi_line" => "multi_line",
+                "dot_matches_new_line" => "dot_matches_new_line",
+                "crlf" => "crlf",
+                "swap_greed" => "swap_greed",
+                "ignore_whitespace" => "ignore_whitespace",
+                "octal" => "octal",
+                "size_limit" => "size_limit",
+                "dfa_size_limit" => "dfa_size_limit",
+                "nest_limit" => "nest_limit",
+                "line_terminator" => "line_terminator",
+                _ => return None,
+            },
+        };
+        Some(method)
+    }
+}
+
+impl Parse for RegexOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if !input.peek(Ident) {
+            return Err(input.error("Expected an option (name = value)"));
+        }
+        let name: Ident = input.parse()?;
+        let Some(kind) = option_kind(&name.to_string()) else {
+            return Err(Error::new(name.span(), format!("Unknown option: {}", name)));
+        };
+        let _: Token![=] = input.parse()?;
+        let value: Lit = input.parse()?;
+        let value = match (kind, value) {
+            (OptionKind::Bool, Lit::Bool(value)) => OptionValue::Bool(value.value),
+            (OptionKind::Number(max), Lit::Int(value)) => {
+                let number: usize = value.base10_parse()?;
+                if number > max {
+                    return Err(Error::new(
+                        value.span(),
+                        format!("The value of {} must be at most {}", name, max),
+                    ));
+                }
+                OptionValue::Number(number)
+            }
+            (OptionKind::Bool, value) => {
+                return Err(Error::new(
+                    value.span(),
+                    format!("The value of {} must be true or false", name),
+                ))
+            }
+            (OptionKind::Number(_), value) => {
+                return Err(Error::new(
+                    value.span(),
+                    format!("The value of {} must be a number", name),
+                ))
+            }
+        };
+        Ok(RegexOption { name, value })
+    }
+}
+
+impl Parse for RegexOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut options: Vec<RegexOption> = vec![];
+        let mut examples: Option<Examples> = None;
+        while !input.is_empty() {
+            if input.peek(Comma) {
+                let _: Comma = input.parse()?;
+                continue;
+            }
+            if Examples::peek(input) {
+                let new_examples: Examples = input.parse()?;
+                if examples.is_some() {
+                    return Err(Error::new(new_examples.span(), "Duplicate examples"));
+                }
+                examples = Some(new_examples);
+                continue;
+            }
+            let option: RegexOption = input.parse()?;
+            if options.iter().any(|o| o.name == option.name) {
+                return Err(Error::new(
+                    option.name.span(),
+                    format!("Duplicate option {}", option.name),
+                ));
+            }
+            options.push(option);
+        }
+        Ok(RegexOptions { options, examples })
+    }
+}
@@ -0,0 +1,51 @@
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use regex::Regex;
+use syn::parse::Parser;
+
+use crate::dsl::Dsl;
+use crate::error::DslError;
+use crate::functions::parse_list::parse_list_to_vec;
+
+/// Parse a DSL text (with the same syntax as the `regex_dsl!` macro) into a regular expression.
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl_core::parse_dsl;
+///
+/// let regex = parse_dsl(r#"
+///     beginning_of_input,
+///     repeat {
+///         #letter
+///     },
+///     maybe {
+///         concat {
+///             '-',
+///             times {
+///                 exactly: 2,
+///                 digit
+///             }
+///         }
+///     },
+///     end_of_input
+/// "#).unwrap();
+/// assert!(regex.is_match("abc-12"));
+/// assert!(!regex.is_match("abc-1"));
+///
+/// let error = parse_dsl("repeat { no_such_word }").unwrap_err();
+/// assert_eq!(error.to_string(), "Unknown word: no_such_word at line 1, column 10");
+/// ```
+pub fn parse_dsl(dsl: &str) -> Result<Regex, DslError> {
+    Dsl::parse(dsl)?.build()
+}
+
+impl Dsl {
+    /// Parse a DSL text (with the same syntax as the `regex_dsl!` macro), see [`parse_dsl`].
+    pub fn parse(dsl: &str) -> Result<Dsl, DslError> {
+        let tokens =
+            TokenStream::from_str(dsl).map_err(|e| DslError::new(format!("Invalid DSL: {}", e)))?;
+        let dsls: Vec<Dsl> = parse_list_to_vec.parse2(tokens)?;
+        Ok(Dsl::concat(&dsls))
+    }
+}
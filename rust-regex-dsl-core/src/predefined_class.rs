@@ -0,0 +1,75 @@
+#[cfg(feature = "parse")]
+use proc_macro2::Span;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+use regex::Regex;
+#[cfg(feature = "parse")]
+use syn::parse::Parse;
+#[cfg(feature = "parse")]
+use syn::parse::ParseStream;
+#[cfg(feature = "parse")]
+use syn::{Error, Ident, Lit, LitFloat, LitInt, LitStr, Result, Token};
+
+use crate::dsl::Dsl;
+use crate::error::DslError;
+
+#[derive(Debug)]
+pub struct PredefineClass {
+    pub regex: String,
+}
+
+impl PredefineClass {
+    pub fn new(class_name: &str, positive: bool) -> std::result::Result<Self, DslError> {
+        let letter = if positive { 'p' } else { 'P' };
+        let regex = format!("\\{}{{{}}}", letter, class_name);
+        if Regex::new(regex.as_str()).is_err() {
+            return Err(DslError::new(format!("Unknown regex class {}", class_name)));
+        }
+        Ok(PredefineClass { regex })
+    }
+
+    pub fn to_dsl(&self) -> Dsl {
+        Dsl::new(&self.regex, false)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl Parse for PredefineClass {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        let positive = if lookahead.peek(Token![#]) {
+            let _: Token![#] = input.parse()?;
+            true
+        } else if lookahead.peek(Token![~]) {
+            let _: Token![~] = input.parse()?;
+            false
+        } else {
+            return Err(lookahead.error());
+        };
+        let (mut class_name, span) = parse_name(input, false)?;
+        if input.peek(Token![=]) && !input.peek(Token![==]) {
+            let _: Token![=] = input.parse()?;
+            let (value, _) = parse_name(input, true)?;
+            class_name = format!("{}={}", class_name, value);
+        }
+        PredefineClass::new(&class_name, positive).map_err(|e| Error::new(span, e.message()))
+    }
+}
+
+/// A class name (or a value of a class property), either an identifier or a string (i.e. `#Greek`, `#"Greek"` or `#age = "3.0"`).
+#[cfg(feature = "parse")]
+fn parse_name(input: ParseStream, value: bool) -> Result<(String, Span)> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(Ident) {
+        let ident: Ident = input.parse()?;
+        Ok((ident.to_string(), ident.span()))
+    } else if lookahead.peek(LitStr) {
+        let str: LitStr = input.parse()?;
+        Ok((str.value(), str.span()))
+    } else if value && (lookahead.peek(LitInt) || lookahead.peek(LitFloat)) {
+        let lit: Lit = input.parse()?;
+        Ok((lit.to_token_stream().to_string(), lit.span()))
+    } else {
+        Err(lookahead.error())
+    }
+}
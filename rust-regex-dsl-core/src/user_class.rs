@@ -0,0 +1,113 @@
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::token::Colon;
+use syn::token::Comma;
+use syn::LitChar;
+use syn::LitStr;
+use syn::{Error, Ident, Result, Token};
+
+use crate::class::Class;
+use crate::dsl::Dsl;
+use crate::group::parse_group;
+use crate::predefined_class::PredefineClass;
+
+pub struct UserClass {
+    class: Class,
+    use_me: bool,
+}
+
+struct ClassContent {
+    class: Class,
+}
+
+impl UserClass {
+    pub fn into_dsl(self) -> Dsl {
+        if self.use_me {
+            Dsl::any_of(self.class)
+        } else {
+            Dsl::not_any_of(self.class)
+        }
+    }
+    pub fn parse(input: ParseStream, use_me: bool) -> Result<Self> {
+        let content: ClassContent = input.parse()?;
+        Ok(UserClass {
+            class: content.class,
+            use_me,
+        })
+    }
+}
+
+impl Parse for ClassContent {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut class = Class::new();
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Comma) {
+                let _: Comma = input.parse()?;
+            } else {
+                class = parse_element(class, input)?;
+            }
+        }
+        if class.is_empty() {
+            return Err(input.error("Empty class is not supported"));
+        }
+        Ok(ClassContent { class })
+    }
+}
+
+fn parse_element(class: Class, input: ParseStream) -> Result<Class> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(LitStr) {
+        let str: LitStr = input.parse()?;
+        if str.value().is_empty() {
+            Err(Error::new(str.span(), "Empty string is not supported"))
+        } else {
+            Ok(class.chars(&str.value()))
+        }
+    } else if lookahead.peek(LitChar) {
+        let chr: LitChar = input.parse()?;
+        Ok(class.char(chr.value()))
+    } else if lookahead.peek(Token![#]) || lookahead.peek(Token![~]) {
+        let cls: PredefineClass = input.parse()?;
+        Ok(class.predefined(&cls))
+    } else if lookahead.peek(Ident) {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "from" => parse_range(class, input),
+            "intersect" => Ok(class.intersect(parse_operation(input)?)),
+            "subtract" => Ok(class.subtract(parse_operation(input)?)),
+            "xor" => Ok(class.xor(parse_operation(input)?)),
+            "any_of" => Ok(class.any_of(parse_operation(input)?)),
+            "not_any_of" => Ok(class.not_any_of(parse_operation(input)?)),
+            word => class
+                .word(word)
+                .map_err(|e| Error::new(ident.span(), e.message())),
+        }
+    } else {
+        Err(lookahead.error())
+    }
+}
+
+fn parse_range(class: Class, input: ParseStream) -> Result<Class> {
+    let _: Colon = input.parse()?;
+    let from: LitChar = input.parse()?;
+    let lookahead = input.lookahead1();
+    if lookahead.peek(Comma) {
+        let _: Comma = input.parse()?;
+    }
+    let ident: Ident = input.parse()?;
+    if ident != "to" {
+        return Err(Error::new(ident.span(), "Expecting to"));
+    }
+    let _: Colon = input.parse()?;
+    let to: LitChar = input.parse()?;
+    Ok(class.range(from.value(), to.value()))
+}
+
+fn parse_operation(input: ParseStream) -> Result<Class> {
+    let Some(group) = parse_group(input)? else {
+        return Err(input.error("Missing content"));
+    };
+    let content: ClassContent = group.parse()?;
+    Ok(content.class)
+}
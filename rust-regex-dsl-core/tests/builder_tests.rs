@@ -0,0 +1,153 @@
+use rust_regex_dsl_core::{Class, Dsl, Flag};
+
+#[test]
+fn eq_is_escaped() {
+    let regex = Dsl::eq("1+1=2?").build().unwrap();
+    assert_eq!(regex.as_str(), "1\\+1=2\\?");
+    assert!(regex.is_match("1+1=2?"));
+    assert!(!regex.is_match("11=2"));
+}
+
+#[test]
+fn concat_and_any() {
+    let dsl = Dsl::concat([
+        Dsl::word("beginning_of_input").unwrap(),
+        Dsl::any([Dsl::eq("foo"), Dsl::eq("bar")]),
+        Dsl::eq("!"),
+        Dsl::word("end_of_input").unwrap(),
+    ]);
+    assert_eq!(dsl.as_str(), "\\A(?:(?:foo)|(?:bar))!\\z");
+    let regex = dsl.build().unwrap();
+    assert!(regex.is_match("foo!"));
+    assert!(regex.is_match("bar!"));
+    assert!(!regex.is_match("baz!"));
+}
+
+#[test]
+fn times() {
+    let digit = Dsl::word("digit").unwrap();
+    assert_eq!(
+        Dsl::from(Dsl::times(digit.clone()).exactly(3)).as_str(),
+        "\\d{3}"
+    );
+    assert_eq!(
+        Dsl::from(Dsl::times(digit.clone()).at_least(2)).as_str(),
+        "\\d{2,}"
+    );
+    assert_eq!(
+        Dsl::from(Dsl::times(digit.clone()).at_least(2).at_most(4).lazy()).as_str(),
+        "\\d{2,4}?"
+    );
+    assert_eq!(Dsl::from(Dsl::repeat(Dsl::eq("ab"))).as_str(), "(?:ab)+");
+    assert_eq!(
+        Dsl::from(Dsl::maybe_repeat(digit.clone()).lazy()).as_str(),
+        "\\d*?"
+    );
+    assert_eq!(Dsl::from(Dsl::maybe(digit)).as_str(), "\\d?");
+}
+
+#[test]
+fn classes() {
+    let hex = Dsl::any_of(Class::new().word("digit").unwrap().range('a', 'f'));
+    let regex = Dsl::from(Dsl::repeat(hex)).build().unwrap();
+    assert!(regex.is_match("ff00"));
+    assert!(!regex.is_match("g"));
+
+    let not_vowel = Dsl::not_any_of(Class::new().chars("aeiou"));
+    let regex = not_vowel.build().unwrap();
+    assert!(regex.is_match("b"));
+    assert!(!regex.is_match("a"));
+
+    let regex = Dsl::class("Greek").unwrap().build().unwrap();
+    assert!(regex.is_match("α"));
+    assert!(Dsl::class("NoSuchClass").is_err());
+    assert!(Dsl::not_class("Greek")
+        .unwrap()
+        .build()
+        .unwrap()
+        .is_match("a"));
+
+    let letters = Class::new()
+        .word("ascii_alpha")
+        .unwrap()
+        .not_any_of(Class::new().word("not_ascii_digit").unwrap());
+    let regex = Dsl::any_of(letters).build().unwrap();
+    assert!(regex.is_match("a"));
+    assert!(regex.is_match("1"));
+    assert!(!regex.is_match("-"));
+    assert!(Dsl::class("Script=Greek")
+        .unwrap()
+        .build()
+        .unwrap()
+        .is_match("α"));
+    assert!(Dsl::word("ascii_ascii").is_err());
+}
+
+#[test]
+fn groups() {
+    let dsl = Dsl::concat([
+        Dsl::named_group("key", Dsl::repeat(Dsl::word("word_character").unwrap())),
+        Dsl::eq("="),
+        Dsl::group(Dsl::repeat(Dsl::word("digit").unwrap())),
+    ]);
+    let regex = dsl.build().unwrap();
+    let captures = regex.captures("size=42").unwrap();
+    assert_eq!(&captures["key"], "size");
+    assert_eq!(&captures[2], "42");
+}
+
+#[test]
+fn apply() {
+    let dsl = Dsl::concat([
+        Dsl::apply().set(Flag::CaseInsensitive).on(Dsl::eq("foo")),
+        Dsl::eq("Bar"),
+    ]);
+    let regex = dsl.build().unwrap();
+    assert!(regex.is_match("FOOBar"));
+    assert!(!regex.is_match("fooBAR"));
+
+    let dsl = Dsl::concat([
+        Dsl::apply().set(Flag::CaseInsensitive).into(),
+        Dsl::eq("foo"),
+    ]);
+    assert_eq!(dsl.as_str(), "(?i)(?:foo)");
+}
+
+#[test]
+fn bytes() {
+    let regex = Dsl::concat([Dsl::byte(0xFF), Dsl::word("any_byte").unwrap()])
+        .build_bytes()
+        .unwrap();
+    assert!(regex.is_match(b"\xFF\x00"));
+    assert!(Dsl::byte(0xFF).build().is_err());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Dsl::word("no_such_word").unwrap_err().to_string(),
+        "Unknown word: no_such_word"
+    );
+    assert!(Dsl::regex("[a-z").is_err());
+    assert!(Dsl::named_group("1", Dsl::eq("a")).build().is_err());
+    assert!(Dsl::concat([]).build().is_err());
+}
+
+#[cfg(feature = "fancy")]
+#[test]
+fn fancy() {
+    let dsl = Dsl::concat([
+        Dsl::lookbehind(Dsl::eq("$")),
+        Dsl::named_group("amount", Dsl::repeat(Dsl::word("digit").unwrap())),
+        Dsl::negative_lookahead(Dsl::eq(".")),
+        Dsl::eq(" "),
+        Dsl::backreference("amount"),
+    ]);
+    assert_eq!(dsl.as_str(), "(?<=\\$)(?<amount>\\d+)(?!\\.) \\k<amount>");
+    let regex = dsl.build_fancy().unwrap();
+    assert!(regex.is_match("$10 10").unwrap());
+    assert!(!regex.is_match("$10 11").unwrap());
+    assert!(!regex.is_match("10 10").unwrap());
+    assert!(dsl.build().is_err());
+    assert!(Dsl::regex("(?=a)").is_ok());
+}
@@ -0,0 +1,156 @@
+use regex_syntax::Parser;
+use rust_regex_dsl_core::{parse_dsl, Dsl};
+use rust_regex_dsl_creator::ToDsl;
+
+#[test]
+fn parse_functions_and_words() {
+    let regex = parse_dsl(
+        r#"
+        apply { +case_insensitive },
+        any {
+            eq("foo"),
+            regex("[0-9]+"),
+        },
+        any_of { '_', from: 'x' to: 'z' },
+        not_any_of { #digit },
+        ~letter,
+        word_boundary,
+        "#,
+    )
+    .unwrap();
+    assert!(regex.is_match("FOO_a.b"));
+    assert!(regex.is_match("12Y!.b"));
+    assert!(!regex.is_match("foo_1.b"));
+}
+
+#[test]
+fn parse_groups() {
+    let regex = parse_dsl(
+        "group { name: key, repeat { word_character } }, '=', group { repeat { digit } }",
+    )
+    .unwrap();
+    let captures = regex.captures("a=1").unwrap();
+    assert_eq!(&captures["key"], "a");
+    assert_eq!(&captures[2], "1");
+}
+
+#[test]
+fn parse_is_the_same_as_the_builder() {
+    let parsed = Dsl::parse("times { lazy, at_least: 2, digit }").unwrap();
+    let built = Dsl::times(Dsl::word("digit").unwrap()).at_least(2).lazy();
+    assert_eq!(parsed.as_str(), Dsl::from(built).as_str());
+}
+
+#[test]
+fn parse_errors() {
+    let error = parse_dsl("concat {\n  'a',\n  no_such_function { 'b' }\n}").unwrap_err();
+    assert_eq!(error.message(), "Unknown function: no_such_function");
+    assert_eq!(error.line(), Some(3));
+    assert_eq!(error.column(), Some(3));
+
+    let error = parse_dsl("#no_such_class").unwrap_err();
+    assert_eq!(error.message(), "Unknown regex class no_such_class");
+
+    let error = parse_dsl("\"unterminated").unwrap_err();
+    assert!(error.message().starts_with("Invalid DSL"));
+    assert_eq!(error.line(), None);
+
+    let error = parse_dsl("").unwrap_err();
+    assert_eq!(error.message(), "Empty regex is not supported");
+
+    let error = parse_dsl("use_fragment(octet)").unwrap_err();
+    assert_eq!(error.message(), "Unknown fragment: octet");
+}
+
+#[test]
+fn parse_what_the_creator_prints() {
+    let cases = [
+        ("[a-z]+[0-9]{2,3}$", vec!["ab12", "x999"], vec!["ab1", "12"]),
+        (
+            "^(?<year>\\d{4})-(?<month>\\d{2})$",
+            vec!["2024-01"],
+            vec!["24-01"],
+        ),
+        ("(?i)foo|bar+?", vec!["FOO", "barrr"], vec!["fo", "ba"]),
+        ("[^\\s,]\\s\\p{Greek}", vec!["a α"], vec!["a,α"]),
+    ];
+    for (regex, matches, rejects) in cases {
+        let dsl = regex.to_strict_dsl().unwrap();
+        let parsed = parse_dsl(&dsl).unwrap();
+        for haystack in matches {
+            assert!(
+                parsed.is_match(haystack),
+                "{} should match {}",
+                regex,
+                haystack
+            );
+        }
+        for haystack in rejects {
+            assert!(
+                !parsed.is_match(haystack),
+                "{} should not match {}",
+                regex,
+                haystack
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_new_classes_and_words() {
+    let regex = parse_dsl(
+        r#"
+        word_start,
+        repeat { any_of { ascii_lower, not_any_of { not_ascii_digit } } },
+        #Script = Greek,
+        word_end,
+        ~"Decimal Number",
+        "#,
+    )
+    .unwrap();
+    assert!(regex.is_match("a1α-"));
+    assert!(!regex.is_match("a1a-"));
+    assert!(!regex.is_match("Aα-"));
+
+    let regex = parse_dsl("apply { +swap_greed, repeat { digit } }").unwrap();
+    assert_eq!(regex.find("123").unwrap().as_str(), "1");
+
+    let error = parse_dsl("#Script = NoSuchScript").unwrap_err();
+    assert_eq!(error.message(), "Unknown regex class Script=NoSuchScript");
+}
+
+#[test]
+fn creator_round_trip_is_exact() {
+    let regexes = [
+        "[[:alpha:][:^digit:]]x[[:ascii:]]",
+        "\\b{start}a\\>\\b{start-half}b\\b{end-half}\\<",
+        "\\p{Script=Greek}\\P{scx!=Latin}\\p{age:3.0}\\p{Decimal Number}",
+        "[a[^bc]][a[b&&c]][^[^a]]",
+        "(?xiU)a+ b(?R)(?m:^$)(?s-i:.)(?-u:a)",
+        "(?:)a",
+        "[\\\\'\"]\\x00\\t",
+        "(?<name>a{2,3}?)(b)",
+    ];
+    for regex in regexes {
+        let dsl = regex.to_strict_dsl().unwrap();
+        let parsed = parse_dsl(&dsl).unwrap();
+        assert_eq!(
+            Parser::new().parse(parsed.as_str()).unwrap(),
+            Parser::new().parse(regex).unwrap(),
+            "{} was converted to {}",
+            regex,
+            dsl
+        );
+    }
+
+    // An empty branch is converted to maybe, so only the behavior is the same.
+    for (regex, expected) in [("a|", "a"), ("|a", ""), ("a||b|c", "a"), ("b||a|c", "")] {
+        let dsl = regex.to_strict_dsl().unwrap();
+        let parsed = parse_dsl(&dsl).unwrap();
+        assert_eq!(parsed.find("a").unwrap().as_str(), expected, "{}", dsl);
+    }
+
+    assert!("()".to_strict_dsl().is_err());
+    assert!("".to_strict_dsl().is_err());
+    assert_eq!("()".to_dsl().unwrap(), "regex(\"()\")\n");
+}
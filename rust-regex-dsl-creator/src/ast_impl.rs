@@ -1,10 +1,10 @@
 use std::fmt::Display;
 
 use regex_syntax::ast::{
-    Alternation, Assertion, AssertionKind, Ast, ClassBracketed, ClassPerl, ClassPerlKind, ClassSet,
-    ClassSetBinaryOp, ClassSetBinaryOpKind, ClassSetItem, ClassUnicode, ClassUnicodeKind, Concat,
-    Flag, FlagsItemKind, Group, GroupKind, Literal, Repetition, RepetitionKind, RepetitionRange,
-    SetFlags,
+    Alternation, Assertion, AssertionKind, Ast, ClassAscii, ClassAsciiKind, ClassBracketed,
+    ClassPerl, ClassPerlKind, ClassSet, ClassSetBinaryOp, ClassSetBinaryOpKind, ClassSetItem,
+    ClassUnicode, ClassUnicodeKind, ClassUnicodeOpKind, Concat, Flag, Flags, FlagsItemKind, Group,
+    GroupKind, Literal, Repetition, RepetitionKind, RepetitionRange, SetFlags,
 };
 
 use crate::printer::Printer;
@@ -17,7 +17,7 @@ pub trait Builder {
     {
         if !self.print_basic_ast(printer) {
             let regex = self.to_string();
-            printer.println(format!("regex(\"{}\")", escape_string(&regex)));
+            printer.fallback(format!("regex(\"{}\")", escape_string(&regex)), regex);
         }
     }
 }
@@ -53,9 +53,13 @@ impl Builder for ClassSet {
 }
 impl Builder for ClassBracketed {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
+        if let (false, ClassSet::Item(ClassSetItem::Ascii(cls))) = (self.negated, &self.kind) {
+            // `[[:alpha:]]` is just `ascii_alpha`
+            return cls.print_basic_ast(printer);
+        }
         let mut indented = Printer::new();
 
-        if !self.kind.print_basic_ast(&mut indented) {
+        if !self.kind.print_basic_ast(&mut indented) || indented.is_empty() {
             return false;
         }
         let func = if !self.negated {
@@ -72,14 +76,20 @@ impl Builder for ClassBracketed {
 impl Builder for ClassSetItem {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
         match self {
+            ClassSetItem::Empty(_) => true,
             ClassSetItem::Literal(lit) => {
-                printer.println(format!("'{}'", lit.c));
+                printer.println(format!("'{}'", escape_char_literal(&lit.c)));
                 true
             }
             ClassSetItem::Range(range) => {
-                printer.println(format!("from: '{}', to: '{}'", range.start.c, range.end.c));
+                printer.println(format!(
+                    "from: '{}', to: '{}'",
+                    escape_char_literal(&range.start.c),
+                    escape_char_literal(&range.end.c)
+                ));
                 true
             }
+            ClassSetItem::Ascii(cls) => cls.print_basic_ast(printer),
             ClassSetItem::Unicode(cls) => cls.print_basic_ast(printer),
             ClassSetItem::Perl(cls) => cls.print_basic_ast(printer),
             ClassSetItem::Union(u) => {
@@ -90,17 +100,37 @@ impl Builder for ClassSetItem {
                 }
                 true
             }
-            ClassSetItem::Bracketed(c) => {
-                if c.negated {
-                    false
-                } else {
-                    c.kind.print_basic_ast(printer)
-                }
-            }
-            _ => false,
+            ClassSetItem::Bracketed(c) => match c.kind {
+                // A union can be added as is, anything else must keep its brackets.
+                ClassSet::Item(_) if !c.negated => c.kind.print_basic_ast(printer),
+                _ => c.print_basic_ast(printer),
+            },
         }
     }
 }
+impl Builder for ClassAscii {
+    fn print_basic_ast(&self, printer: &mut Printer) -> bool {
+        let name = match self.kind {
+            ClassAsciiKind::Alnum => "ascii_alnum",
+            ClassAsciiKind::Alpha => "ascii_alpha",
+            ClassAsciiKind::Ascii => "ascii",
+            ClassAsciiKind::Blank => "ascii_blank",
+            ClassAsciiKind::Cntrl => "ascii_cntrl",
+            ClassAsciiKind::Digit => "ascii_digit",
+            ClassAsciiKind::Graph => "ascii_graph",
+            ClassAsciiKind::Lower => "ascii_lower",
+            ClassAsciiKind::Print => "ascii_print",
+            ClassAsciiKind::Punct => "ascii_punct",
+            ClassAsciiKind::Space => "ascii_space",
+            ClassAsciiKind::Upper => "ascii_upper",
+            ClassAsciiKind::Word => "ascii_word",
+            ClassAsciiKind::Xdigit => "ascii_xdigit",
+        };
+        let prefix = if self.negated { "not_" } else { "" };
+        printer.println(format!("{}{}", prefix, name));
+        true
+    }
+}
 impl Builder for ClassSetBinaryOp {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
         if !self.lhs.print_basic_ast(printer) {
@@ -125,40 +155,48 @@ impl Builder for ClassSetBinaryOp {
 impl Builder for SetFlags {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
         let mut indented = Printer::new();
+        if !self.flags.print_basic_ast(&mut indented) {
+            return false;
+        }
+        if !indented.is_empty() {
+            printer.println("apply {".to_string());
+            printer.append(&indented);
+            printer.println("}".to_string());
+        }
+        true
+    }
+}
+impl Builder for Flags {
+    fn print_basic_ast(&self, printer: &mut Printer) -> bool {
         let mut apply = true;
-        for flag in &self.flags.items {
+        for flag in &self.items {
             match flag.kind {
                 FlagsItemKind::Negation => apply = false,
                 FlagsItemKind::Flag(flag) => {
-                    if !(apply, flag).print_basic_ast(&mut indented) {
+                    if !(apply, flag).print_basic_ast(printer) {
                         return false;
                     }
                 }
             }
         }
-        printer.println("apply {".to_string());
-        printer.append(&indented);
-        printer.println("}".to_string());
         true
     }
 }
 impl Builder for (bool, Flag) {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
         let name = match self.1 {
-            Flag::CaseInsensitive => Some("case_insensitive"),
-            Flag::MultiLine => Some("multi_line"),
-            Flag::DotMatchesNewLine => Some("allow_dot"),
-            Flag::CRLF => Some("allow_dot"),
-            _ => None,
+            Flag::CaseInsensitive => "case_insensitive",
+            Flag::MultiLine => "multi_line",
+            Flag::DotMatchesNewLine => "allow_dot",
+            Flag::CRLF => "enables_crlf_mode",
+            Flag::SwapGreed => "swap_greed",
+            Flag::Unicode => "unicode",
+            // The white spaces are already ignored by the parser.
+            Flag::IgnoreWhitespace => return true,
         };
         let prefix = if self.0 { "+" } else { "-" };
-        match name {
-            Some(name) => {
-                printer.println(format!("{}{}", prefix, name));
-                true
-            }
-            None => false,
-        }
+        printer.println(format!("{}{}", prefix, name));
+        true
     }
 }
 impl Builder for Group {
@@ -167,6 +205,9 @@ impl Builder for Group {
             GroupKind::CaptureIndex(_) => {
                 let mut indented = Printer::new();
                 self.ast.print_ast(&mut indented);
+                if indented.is_empty() {
+                    return false;
+                }
                 printer.println("group {".to_string());
                 printer.append(&indented);
                 printer.println("}".to_string());
@@ -177,27 +218,24 @@ impl Builder for Group {
             } => {
                 let mut indented = Printer::new();
                 indented.println(format!("name: {}", name.name));
-                self.ast.print_ast(&mut indented);
+                let mut content = Printer::new();
+                self.ast.print_ast(&mut content);
+                if content.is_empty() {
+                    return false;
+                }
+                indented.append_raw(&content);
                 printer.println("group {".to_string());
                 printer.append(&indented);
                 printer.println("}".to_string());
             }
             GroupKind::NonCapturing(flags) => {
-                if flags.items.is_empty() {
+                let mut indented = Printer::new();
+                if !flags.print_basic_ast(&mut indented) {
+                    return false;
+                }
+                if indented.is_empty() {
                     self.ast.print_ast(printer);
                 } else {
-                    let mut indented = Printer::new();
-                    let mut apply = true;
-                    for flag in &flags.items {
-                        match flag.kind {
-                            FlagsItemKind::Negation => apply = false,
-                            FlagsItemKind::Flag(flag) => {
-                                if !(apply, flag).print_basic_ast(&mut indented) {
-                                    return false;
-                                }
-                            }
-                        }
-                    }
                     self.ast.print_ast(&mut indented);
                     printer.println("apply {".to_string());
                     printer.append(&indented);
@@ -229,7 +267,12 @@ impl Builder for Repetition {
         if !self.greedy {
             indented.println("lazy".to_string())
         }
-        self.ast.print_ast(&mut indented);
+        let mut content = Printer::new();
+        self.ast.print_ast(&mut content);
+        if content.is_empty() {
+            return false;
+        }
+        indented.append_raw(&content);
         printer.println(prefix.to_string());
         printer.append(&indented);
         printer.println("}".to_string());
@@ -258,7 +301,12 @@ impl Builder for Assertion {
             AssertionKind::EndText => "end_of_input",
             AssertionKind::WordBoundary => "word_boundary",
             AssertionKind::NotWordBoundary => "not_word_boundary",
-            _ => return false,
+            AssertionKind::WordBoundaryStart | AssertionKind::WordBoundaryStartAngle => {
+                "word_start"
+            }
+            AssertionKind::WordBoundaryEnd | AssertionKind::WordBoundaryEndAngle => "word_end",
+            AssertionKind::WordBoundaryStartHalf => "word_start_half",
+            AssertionKind::WordBoundaryEndHalf => "word_end_half",
         };
         printer.println(name.to_string());
         true
@@ -266,24 +314,72 @@ impl Builder for Assertion {
 }
 impl Builder for Alternation {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
-        let mut indented = Printer::new();
+        let mut branches = vec![];
+        // The number of branches that are preferred over an empty branch.
+        let mut before_empty = None;
         for ast in &self.asts {
-            ast.print_ast(&mut indented)
+            let mut branch = Printer::new();
+            ast.print_ast(&mut branch);
+            if branch.is_empty() {
+                before_empty.get_or_insert(branches.len());
+            } else {
+                branches.push(branch);
+            }
+        }
+        match before_empty {
+            _ if branches.is_empty() => {}
+            None => print_any(printer, &branches),
+            // `a|` is `a?`
+            Some(count) if count == branches.len() => print_maybe(printer, &branches, false),
+            // `|a` is `a??`
+            Some(0) => print_maybe(printer, &branches, true),
+            // `a||b` is `a|b??`
+            Some(count) => {
+                let mut preferred = Printer::new();
+                print_maybe(&mut preferred, &branches[count..], true);
+                let mut branches = branches;
+                branches.truncate(count);
+                branches.push(preferred);
+                print_any(printer, &branches);
+            }
         }
-        printer.println("any {".to_string());
-        printer.append(&indented);
-        printer.println("}".to_string());
         true
     }
 }
+fn print_any(printer: &mut Printer, branches: &[Printer]) {
+    if branches.len() == 1 {
+        printer.append_raw(&branches[0]);
+        return;
+    }
+    let mut indented = Printer::new();
+    for branch in branches {
+        indented.append_raw(branch);
+    }
+    printer.println("any {".to_string());
+    printer.append(&indented);
+    printer.println("}".to_string());
+}
+fn print_maybe(printer: &mut Printer, branches: &[Printer], lazy: bool) {
+    let mut indented = Printer::new();
+    if lazy {
+        indented.println("lazy".to_string());
+    }
+    print_any(&mut indented, branches);
+    printer.println("maybe {".to_string());
+    printer.append(&indented);
+    printer.println("}".to_string());
+}
 impl Builder for ClassUnicode {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
-        let letter = if self.negated { "~" } else { "#" };
-        let name = match &self.kind {
-            ClassUnicodeKind::Named(name) => name.to_string(),
-            ClassUnicodeKind::OneLetter(l) => l.to_string(),
-            _ => return false,
+        let (name, negated) = match &self.kind {
+            ClassUnicodeKind::Named(name) => (class_name(name), self.negated),
+            ClassUnicodeKind::OneLetter(l) => (l.to_string(), self.negated),
+            ClassUnicodeKind::NamedValue { op, name, value } => (
+                format!("{} = {}", class_name(name), class_name(value)),
+                self.negated != (*op == ClassUnicodeOpKind::NotEqual),
+            ),
         };
+        let letter = if negated { "~" } else { "#" };
         printer.println(format!("{}{}", letter, name));
         true
     }
@@ -296,30 +392,38 @@ impl Builder for Literal {
 }
 impl Builder for Concat {
     fn print_basic_ast(&self, printer: &mut Printer) -> bool {
-        let in_concat = combine_concat(&self.asts);
-        match in_concat.len() {
-            0 => true,
-            1 => in_concat[0].print_basic_ast(printer),
+        let elements: Vec<_> = combine_concat(&self.asts)
+            .into_iter()
+            .map(|element| {
+                let mut printer = Printer::new();
+                element.print_ast(&mut printer);
+                printer
+            })
+            .filter(|printer| !printer.is_empty())
+            .collect();
+        match elements.len() {
+            0 => {}
+            1 => printer.append_raw(&elements[0]),
             _ => {
                 printer.println("concat {".to_string());
                 let mut indented = Printer::new();
-                for element in in_concat {
-                    element.print_ast(&mut indented)
+                for element in elements {
+                    indented.append_raw(&element);
                 }
                 printer.append(&indented);
                 printer.println("}".to_string());
-                true
             }
         }
+        true
     }
 }
 
-enum InConcat<'h> {
+pub(crate) enum InConcat<'h> {
     Str(String),
     Char(char),
     Ast(&'h Ast),
 }
-fn combine_concat(asts: &[Ast]) -> Vec<InConcat<'_>> {
+pub(crate) fn combine_concat(asts: &[Ast]) -> Vec<InConcat<'_>> {
     let mut ret = Vec::new();
     let mut current_str = String::new();
     let mut chr = None;
@@ -391,6 +495,38 @@ fn escape_char(chr: &char) -> String {
         '\t' => "\\t".to_string(),
         '\\' => "\\\\".to_string(),
         '\"' => "\\\"".to_string(),
+        _ if chr.is_control() => format!("\\u{{{:x}}}", *chr as u32),
         _ => format!("{}", chr),
     }
 }
+fn escape_char_literal(chr: &char) -> String {
+    match chr {
+        '\'' => "\\'".to_string(),
+        '\"' => "\"".to_string(),
+        _ => escape_char(chr),
+    }
+}
+
+const KEYWORDS: [&str; 52] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// A unicode class name (or value) - as is if it's an identifier, or as a string otherwise.
+fn class_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_ident = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && !KEYWORDS.contains(&name);
+    if is_ident {
+        name.to_string()
+    } else {
+        format!("\"{}\"", escape_string(name))
+    }
+}
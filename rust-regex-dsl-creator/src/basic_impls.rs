@@ -1,19 +1,61 @@
 use crate::ast_impl::Builder;
+use crate::error::ToDslError;
+use crate::explain_impl::Explainer;
 use crate::printer::Printer;
-use crate::ToDsl;
+use crate::rust_output::RustOutput;
+use crate::{ToDsl, ToExplanation};
 use regex::Error;
 use regex::Regex;
 use regex_syntax::ast::parse::Parser;
 
 impl<T: ToString> ToDsl for T {
     fn to_dsl(&self) -> Result<String, Error> {
+        Ok(print(&self.to_string())?.to_string())
+    }
+
+    fn to_strict_dsl(&self) -> Result<String, ToDslError> {
+        let str = self.to_string();
+        let printer = print(&str)?;
+        if let Some(regex) = printer.fallbacks().first() {
+            return Err(ToDslError::Unsupported(regex.clone()));
+        }
+        if printer.is_empty() {
+            return Err(ToDslError::Unsupported(str));
+        }
+        Ok(printer.to_string())
+    }
+
+    fn to_rust(&self, output: &RustOutput) -> Result<String, ToDslError> {
+        let str = self.to_string();
+        let printer = print(&str)?;
+        if printer.is_empty() {
+            return Err(ToDslError::Unsupported(str));
+        }
+        let regex = Regex::new(&str)?;
+        let capture_names: Vec<_> = regex.capture_names().flatten().collect();
+        Ok(output.print(&printer.to_string(), &capture_names))
+    }
+}
+
+impl<T: ToString> ToExplanation for T {
+    fn to_explanation(&self) -> Result<String, Error> {
         let str = self.to_string();
         Regex::new(&str)?;
         let mut parser = Parser::new();
         // This must pass since the regular expression is valid
         let ast = parser.parse(&str).unwrap();
-        let mut printer = Printer::new();
-        ast.print_ast(&mut printer);
-        Ok(printer.to_string())
+        let mut explanation = String::new();
+        ast.explain().print(0, "", &mut explanation);
+        Ok(explanation)
     }
 }
+
+fn print(str: &str) -> Result<Printer, Error> {
+    Regex::new(str)?;
+    let mut parser = Parser::new();
+    // This must pass since the regular expression is valid
+    let ast = parser.parse(str).unwrap();
+    let mut printer = Printer::new();
+    ast.print_ast(&mut printer);
+    Ok(printer)
+}
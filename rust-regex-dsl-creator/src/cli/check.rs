@@ -0,0 +1,61 @@
+use clap::Args;
+use rust_regex_dsl_core::parse_dsl;
+use std::fs;
+use std::io::{read_to_string, stdin};
+use std::path::PathBuf;
+
+use super::{Format, Output};
+
+#[derive(Args)]
+pub struct Check {
+    /// The DSL files to validate. Without files, the standard input is used.
+    files: Vec<PathBuf>,
+    /// The output format (only json is different than plain).
+    #[arg(short = 'o', long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+}
+
+impl Check {
+    pub fn run(self) -> bool {
+        let inputs = if self.files.is_empty() {
+            vec![("<stdin>".to_string(), read_to_string(stdin()))]
+        } else {
+            self.files
+                .iter()
+                .map(|file| (file.display().to_string(), fs::read_to_string(file)))
+                .collect()
+        };
+        let outputs: Vec<_> = inputs
+            .into_iter()
+            .map(|(name, dsl)| {
+                let result = match dsl {
+                    Ok(dsl) => check(&dsl).map_err(|e| match (e.line(), e.column()) {
+                        (Some(line), Some(column)) => {
+                            format!("{}:{}: {}", line, column, e.message())
+                        }
+                        _ => e.message().to_string(),
+                    }),
+                    Err(e) => Err(format!("Could not read the file: {}", e)),
+                };
+                Output {
+                    input: name,
+                    result,
+                }
+            })
+            .collect();
+        if self.format == Format::Json {
+            return self.format.print("regular expression", &outputs);
+        }
+        for output in &outputs {
+            match &output.result {
+                Ok(_) => println!("{}: OK", output.input),
+                Err(error) => eprintln!("{}: {}", output.input, error),
+            }
+        }
+        outputs.iter().all(|output| output.result.is_ok())
+    }
+}
+
+fn check(dsl: &str) -> Result<String, rust_regex_dsl_core::DslError> {
+    Ok(parse_dsl(dsl)?.to_string())
+}
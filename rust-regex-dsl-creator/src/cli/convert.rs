@@ -0,0 +1,71 @@
+use clap::{Args, ValueEnum};
+use rust_regex_dsl_creator::{RustOutput, ToDsl};
+
+use super::{Format, Inputs, Output};
+
+#[derive(Args, Default)]
+pub struct Convert {
+    #[command(flatten)]
+    inputs: Inputs,
+    /// The output format.
+    #[arg(short = 'o', long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+    /// Fail if a regular expression can not be expressed by the DSL (instead of using `regex("...")`).
+    #[arg(long)]
+    strict: bool,
+    /// The macro to create with the rust format.
+    #[arg(long = "macro", value_enum, default_value_t = Macro::RegexDsl)]
+    macro_type: Macro,
+    /// The name of the struct (or the static) for the rust format, by default it's inferred from the first capture group name.
+    #[arg(long)]
+    name: Option<String>,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Macro {
+    /// `regex_dsl! { ... }`
+    #[default]
+    RegexDsl,
+    /// `create_capture!(Name, ...)`
+    CreateCapture,
+    /// `static NAME: LazyLock<Regex> = ...`
+    LazyLock,
+}
+
+impl Convert {
+    pub fn run(self) -> bool {
+        let inputs = match self.inputs.read() {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("Could not read the inputs: {}", e);
+                return false;
+            }
+        };
+        let outputs: Vec<_> = inputs
+            .into_iter()
+            .map(|input| Output {
+                result: self.convert(&input),
+                input: input.trim().to_string(),
+            })
+            .collect();
+        self.format.print("DSL", &outputs)
+    }
+
+    fn convert(&self, input: &str) -> Result<String, String> {
+        let regex = self.inputs.regex(input)?;
+        let dsl = if self.strict {
+            regex.to_strict_dsl().map_err(|e| e.to_string())?
+        } else {
+            regex.to_dsl().map_err(|e| e.to_string())?
+        };
+        if self.format != Format::Rust {
+            return Ok(dsl);
+        }
+        let output = match self.macro_type {
+            Macro::RegexDsl => RustOutput::RegexDsl,
+            Macro::CreateCapture => RustOutput::CreateCapture(self.name.clone()),
+            Macro::LazyLock => RustOutput::LazyLock(self.name.clone()),
+        };
+        regex.to_rust(&output).map_err(|e| e.to_string())
+    }
+}
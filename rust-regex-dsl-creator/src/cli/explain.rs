@@ -0,0 +1,39 @@
+use clap::Args;
+use rust_regex_dsl_creator::ToExplanation;
+
+use super::{Format, Inputs, Output};
+
+#[derive(Args)]
+pub struct Explain {
+    #[command(flatten)]
+    inputs: Inputs,
+    /// The output format (rust is the same as plain).
+    #[arg(short = 'o', long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+}
+
+impl Explain {
+    pub fn run(self) -> bool {
+        let inputs = match self.inputs.read() {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("Could not read the inputs: {}", e);
+                return false;
+            }
+        };
+        let outputs: Vec<_> = inputs
+            .into_iter()
+            .map(|input| Output {
+                result: self.explain(&input),
+                input: input.trim().to_string(),
+            })
+            .collect();
+        self.format.print("the explanation", &outputs)
+    }
+
+    fn explain(&self, input: &str) -> Result<String, String> {
+        let regex = self.inputs.regex(input)?;
+        let explanation = regex.to_explanation().map_err(|e| e.to_string())?;
+        Ok(explanation.trim_end().to_string())
+    }
+}
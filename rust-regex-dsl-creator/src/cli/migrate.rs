@@ -0,0 +1,81 @@
+use clap::Args;
+use rust_regex_dsl_creator::migrate;
+use similar::TextDiff;
+use std::fs;
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct Migrate {
+    /// A Rust file, or a directory with Rust files.
+    path: PathBuf,
+    /// Print the changes as a diff instead of writing them.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl Migrate {
+    pub fn run(self) -> bool {
+        let mut success = true;
+        match self.migrate_path(&self.path, &mut success) {
+            Ok(count) => eprintln!("{} file(s) migrated", count),
+            Err(e) => {
+                eprintln!("Could not migrate {}: {}", self.path.display(), e);
+                success = false;
+            }
+        }
+        success
+    }
+
+    /// Migrate all the Rust files in the path, return the number of files that were changed.
+    fn migrate_path(&self, path: &Path, success: &mut bool) -> Result<usize, IoError> {
+        if path.is_dir() {
+            let mut count = 0;
+            for entry in fs::read_dir(path)? {
+                let path = entry?.path();
+                if path.file_name().is_some_and(|name| name == "target") {
+                    continue;
+                }
+                count += self.migrate_path(&path, success)?;
+            }
+            return Ok(count);
+        }
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            return Ok(0);
+        }
+        let source = fs::read_to_string(path)?;
+        let migration = match migrate(&source) {
+            Ok(migration) => migration,
+            Err(e) => {
+                eprintln!("Could not parse {}: {}", path.display(), e);
+                *success = false;
+                return Ok(0);
+            }
+        };
+        for call in &migration.ambiguous {
+            eprintln!(
+                "{}:{}:{}: `{}` was not migrated, import the type from `regex` or use its full path",
+                path.display(),
+                call.line,
+                call.column,
+                call.function
+            );
+        }
+        let migrated = migration.source;
+        if migrated == source {
+            return Ok(0);
+        }
+        if self.dry_run {
+            let name = path.display().to_string();
+            print!(
+                "{}",
+                TextDiff::from_lines(&source, &migrated)
+                    .unified_diff()
+                    .header(&name, &name)
+            );
+        } else {
+            fs::write(path, migrated)?;
+        }
+        Ok(1)
+    }
+}
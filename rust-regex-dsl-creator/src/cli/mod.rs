@@ -0,0 +1,166 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rust_regex_dsl_core::parse_dsl;
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{read_to_string, stdin, BufRead, Error as IoError};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod check;
+mod convert;
+mod explain;
+mod migrate;
+mod near_miss;
+
+/// Create regular expression DSLs (see https://crates.io/crates/rust-regex-dsl).
+///
+/// Without a command, the regular expressions in the standard input are converted to Markdown (like `convert`).
+#[derive(Parser)]
+#[command(version)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert regular expressions to DSLs.
+    Convert(convert::Convert),
+    /// Describe regular expressions (or DSLs) in plain English.
+    Explain(explain::Explain),
+    /// Create strings that almost match regular expressions (or DSLs), but are rejected by them.
+    NearMiss(near_miss::NearMiss),
+    /// Validate DSL files.
+    Check(check::Check),
+    /// Rewrite the `Regex::new("...")` calls in Rust files to DSL macros.
+    Migrate(migrate::Migrate),
+}
+
+/// The output format.
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum Format {
+    /// A Markdown code block for every input.
+    #[default]
+    Markdown,
+    /// Only the output.
+    Plain,
+    /// A JSON array with an object for every input.
+    Json,
+    /// Rust code (see `--macro`).
+    Rust,
+}
+
+impl Cli {
+    pub fn run(self) -> ExitCode {
+        let command = self
+            .command
+            .unwrap_or_else(|| Command::Convert(convert::Convert::default()));
+        let success = match command {
+            Command::Convert(convert) => convert.run(),
+            Command::Explain(explain) => explain.run(),
+            Command::NearMiss(near_miss) => near_miss.run(),
+            Command::Check(check) => check.run(),
+            Command::Migrate(migrate) => migrate.run(),
+        };
+        if success {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The output (or the error) of a single input.
+struct Output {
+    input: String,
+    result: Result<String, String>,
+}
+
+impl Format {
+    /// Print the outputs (the errors are printed to the standard error), return true if there were no errors.
+    fn print(&self, title: &str, outputs: &[Output]) -> bool {
+        if *self == Format::Json {
+            let outputs: Vec<Value> = outputs
+                .iter()
+                .map(|output| match &output.result {
+                    Ok(result) => json!({ "input": output.input, "output": result }),
+                    Err(error) => json!({ "input": output.input, "error": error }),
+                })
+                .collect();
+            println!("{}", Value::Array(outputs));
+        } else {
+            for output in outputs {
+                match &output.result {
+                    Ok(result) if *self == Format::Markdown => {
+                        println!(
+                            "For `{}` {} would look like:\n```\n{}\n```\n",
+                            output.input, title, result
+                        );
+                    }
+                    Ok(result) => println!("{}", result),
+                    Err(error) => eprintln!("Error with `{}` : {}", output.input, error),
+                }
+            }
+        }
+        outputs.iter().all(|output| output.result.is_ok())
+    }
+}
+
+/// The non empty lines of the files (or of the standard input if there are no files).
+fn read_lines(files: &[PathBuf]) -> Result<Vec<String>, IoError> {
+    let mut lines = vec![];
+    if files.is_empty() {
+        for line in stdin().lock().lines() {
+            lines.push(line?);
+        }
+    } else {
+        for file in files {
+            lines.extend(fs::read_to_string(file)?.lines().map(str::to_string));
+        }
+    }
+    lines.retain(|line| !line.is_empty());
+    Ok(lines)
+}
+
+/// Regular expressions or DSLs to work on.
+#[derive(Args, Default)]
+struct Inputs {
+    /// The regular expressions (or DSLs, with `--dsl`). Without them or files, the standard input is used (a regular
+    /// expression in every line, or a single DSL).
+    inputs: Vec<String>,
+    /// A file with a regular expression in every line (or a DSL file, with `--dsl`).
+    #[arg(short, long = "file")]
+    files: Vec<PathBuf>,
+    /// The inputs are DSLs rather than regular expressions.
+    #[arg(long)]
+    dsl: bool,
+}
+
+impl Inputs {
+    /// All the inputs (from the arguments, the files or the standard input).
+    fn read(&self) -> Result<Vec<String>, IoError> {
+        let mut inputs = self.inputs.clone();
+        if !inputs.is_empty() && self.files.is_empty() {
+            return Ok(inputs);
+        }
+        if !self.dsl {
+            inputs.extend(read_lines(&self.files)?);
+        } else if self.files.is_empty() {
+            inputs.push(read_to_string(stdin())?);
+        } else {
+            for file in &self.files {
+                inputs.push(fs::read_to_string(file)?);
+            }
+        }
+        Ok(inputs)
+    }
+
+    /// The regular expression of an input.
+    fn regex(&self, input: &str) -> Result<String, String> {
+        if self.dsl {
+            Ok(parse_dsl(input).map_err(|e| e.to_string())?.to_string())
+        } else {
+            Ok(input.to_string())
+        }
+    }
+}
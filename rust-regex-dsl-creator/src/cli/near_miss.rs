@@ -0,0 +1,48 @@
+use clap::Args;
+use rust_regex_dsl_creator::Sampler;
+
+use super::{Format, Inputs, Output};
+
+#[derive(Args)]
+pub struct NearMiss {
+    #[command(flatten)]
+    inputs: Inputs,
+    /// The output format (rust is the same as plain).
+    #[arg(short = 'o', long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+    /// The maximal number of strings for every input.
+    #[arg(short = 'n', long, default_value_t = 10)]
+    limit: usize,
+}
+
+impl NearMiss {
+    pub fn run(self) -> bool {
+        let inputs = match self.inputs.read() {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("Could not read the inputs: {}", e);
+                return false;
+            }
+        };
+        let outputs: Vec<_> = inputs
+            .into_iter()
+            .map(|input| Output {
+                result: self.near_misses(&input),
+                input: input.trim().to_string(),
+            })
+            .collect();
+        self.format.print("the near misses", &outputs)
+    }
+
+    /// The near misses, quoted, one in every line.
+    fn near_misses(&self, input: &str) -> Result<String, String> {
+        let regex = self.inputs.regex(input)?;
+        let sampler = Sampler::new(&regex).map_err(|e| e.to_string())?;
+        let near_misses: Vec<_> = sampler
+            .near_misses(self.limit)
+            .iter()
+            .map(|near_miss| format!("{:?}", near_miss))
+            .collect();
+        Ok(near_misses.join("\n"))
+    }
+}
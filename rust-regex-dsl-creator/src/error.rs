@@ -0,0 +1,30 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// An error from [`ToDsl::to_strict_dsl`](crate::ToDsl::to_strict_dsl) or [`ToDsl::to_rust`](crate::ToDsl::to_rust).
+#[derive(Debug)]
+pub enum ToDslError {
+    /// The regular expression is not valid.
+    InvalidRegex(regex::Error),
+    /// A part of the regular expression (the string) has no DSL equivalent.
+    Unsupported(String),
+}
+
+impl Display for ToDslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToDslError::InvalidRegex(e) => e.fmt(f),
+            ToDslError::Unsupported(regex) => {
+                write!(f, "`{}` can not be converted to a DSL", regex)
+            }
+        }
+    }
+}
+
+impl Error for ToDslError {}
+
+impl From<regex::Error> for ToDslError {
+    fn from(e: regex::Error) -> Self {
+        ToDslError::InvalidRegex(e)
+    }
+}
@@ -0,0 +1,477 @@
+use regex_syntax::ast::{
+    Alternation, Assertion, AssertionKind, Ast, ClassAscii, ClassAsciiKind, ClassBracketed,
+    ClassPerl, ClassPerlKind, ClassSet, ClassSetBinaryOpKind, ClassSetItem, ClassUnicode,
+    ClassUnicodeKind, ClassUnicodeOpKind, Concat, Flag, Flags, FlagsItemKind, Group, GroupKind,
+    Literal, Repetition, RepetitionKind, RepetitionRange,
+};
+
+use crate::ast_impl::{combine_concat, InConcat};
+
+/// An explanation of a part of a regular expression.
+pub enum Explanation {
+    /// A single line, with an optional plural form (i.e. `a digit` and `digits`).
+    Item {
+        singular: String,
+        plural: Option<String>,
+    },
+    /// A title with indented parts (every part but the first starts with "or").
+    Block {
+        title: String,
+        parts: Vec<Explanation>,
+    },
+    /// Parts that come one after the other (every part but the first starts with "then").
+    Sequence(Vec<Explanation>),
+}
+
+impl Explanation {
+    fn item(singular: impl Into<String>) -> Self {
+        Explanation::Item {
+            singular: singular.into(),
+            plural: None,
+        }
+    }
+
+    fn items(singular: impl Into<String>, plural: impl Into<String>) -> Self {
+        Explanation::Item {
+            singular: singular.into(),
+            plural: Some(plural.into()),
+        }
+    }
+
+    fn block(title: impl Into<String>, part: Explanation) -> Self {
+        Explanation::Block {
+            title: title.into(),
+            parts: vec![part],
+        }
+    }
+
+    /// Can this be a part of a longer line without being ambiguous.
+    fn is_simple(&self) -> bool {
+        match self {
+            Explanation::Item { singular, .. } => {
+                !singular.contains(", ") && !singular.starts_with("either ")
+            }
+            _ => false,
+        }
+    }
+
+    pub fn print(&self, indent: usize, prefix: &str, out: &mut String) {
+        let spaces = "  ".repeat(indent);
+        match self {
+            Explanation::Item { singular, .. } => {
+                out.push_str(&format!("{}{}{}\n", spaces, prefix, singular));
+            }
+            Explanation::Block { title, parts } => {
+                out.push_str(&format!("{}{}{}:\n", spaces, prefix, title));
+                for (index, part) in parts.iter().enumerate() {
+                    part.print(indent + 1, if index == 0 { "" } else { "or " }, out);
+                }
+            }
+            Explanation::Sequence(parts) if parts.is_empty() => {
+                out.push_str(&format!("{}{}nothing\n", spaces, prefix));
+            }
+            Explanation::Sequence(parts) => {
+                for (index, part) in parts.iter().enumerate() {
+                    part.print(indent, if index == 0 { prefix } else { "then " }, out);
+                }
+            }
+        }
+    }
+}
+
+pub trait Explainer {
+    fn explain(&self) -> Explanation;
+}
+
+impl Explainer for Ast {
+    fn explain(&self) -> Explanation {
+        match self {
+            Ast::Empty(_) => Explanation::Sequence(vec![]),
+            Ast::Flags(flags) => {
+                Explanation::item(format!("from here on: {}", explain_flags(&flags.flags)))
+            }
+            Ast::Literal(lit) => lit.explain(),
+            Ast::Dot(_) => Explanation::items("any character", "any characters"),
+            Ast::Assertion(assertion) => assertion.explain(),
+            Ast::ClassUnicode(cls) => cls.explain(),
+            Ast::ClassPerl(cls) => cls.explain(),
+            Ast::ClassBracketed(cls) => cls.explain(),
+            Ast::Repetition(rep) => rep.explain(),
+            Ast::Group(group) => group.explain(),
+            Ast::Alternation(alts) => alts.explain(),
+            Ast::Concat(cnt) => cnt.explain(),
+        }
+    }
+}
+
+impl Explainer for Literal {
+    fn explain(&self) -> Explanation {
+        Explanation::items(
+            format!("the character {:?}", self.c),
+            format!("{:?} characters", self.c),
+        )
+    }
+}
+
+impl Explainer for Assertion {
+    fn explain(&self) -> Explanation {
+        let name = match self.kind {
+            AssertionKind::StartLine => "beginning of line",
+            AssertionKind::EndLine => "end of line",
+            AssertionKind::StartText => "beginning of input",
+            AssertionKind::EndText => "end of input",
+            AssertionKind::WordBoundary => "a word boundary",
+            AssertionKind::NotWordBoundary => "not a word boundary",
+            AssertionKind::WordBoundaryStart | AssertionKind::WordBoundaryStartAngle => {
+                "start of a word"
+            }
+            AssertionKind::WordBoundaryEnd | AssertionKind::WordBoundaryEndAngle => "end of a word",
+            AssertionKind::WordBoundaryStartHalf => "not after a word character",
+            AssertionKind::WordBoundaryEndHalf => "not before a word character",
+        };
+        Explanation::item(name)
+    }
+}
+
+impl Explainer for ClassPerl {
+    fn explain(&self) -> Explanation {
+        let (singular, plural) = match (self.kind.clone(), self.negated) {
+            (ClassPerlKind::Digit, false) => ("a digit", "digits"),
+            (ClassPerlKind::Digit, true) => ("a non digit", "non digits"),
+            (ClassPerlKind::Space, false) => ("a white space", "white spaces"),
+            (ClassPerlKind::Space, true) => ("a non white space", "non white spaces"),
+            (ClassPerlKind::Word, false) => ("a word character", "word characters"),
+            (ClassPerlKind::Word, true) => ("a non word character", "non word characters"),
+        };
+        Explanation::items(singular, plural)
+    }
+}
+
+impl Explainer for ClassUnicode {
+    fn explain(&self) -> Explanation {
+        if let ClassUnicodeKind::NamedValue { op, name, value } = &self.kind {
+            let op = if self.negated == (*op == ClassUnicodeOpKind::NotEqual) {
+                "is"
+            } else {
+                "is not"
+            };
+            return Explanation::items(
+                format!("a character whose {} {} {}", name, op, value),
+                format!("characters whose {} {} {}", name, op, value),
+            );
+        }
+        let (singular, plural) = if self.negated {
+            ("a character not from", "characters not from")
+        } else {
+            ("a character from", "characters from")
+        };
+        let name = unicode_class(self);
+        Explanation::items(
+            format!("{} {}", singular, name),
+            format!("{} {}", plural, name),
+        )
+    }
+}
+
+impl Explainer for ClassBracketed {
+    fn explain(&self) -> Explanation {
+        if let (false, ClassSet::Item(ClassSetItem::Range(range))) = (self.negated, &self.kind) {
+            if range.start.c == '0' && range.end.c == '9' {
+                return Explanation::items("a digit", "digits");
+            }
+        }
+        let (singular, plural) = if self.negated {
+            (
+                "a character that is not from",
+                "characters that are not from",
+            )
+        } else {
+            ("a character from", "characters from")
+        };
+        let content = class_set(&self.kind);
+        Explanation::items(
+            format!("{} {}", singular, content),
+            format!("{} {}", plural, content),
+        )
+    }
+}
+
+impl Explainer for Repetition {
+    fn explain(&self) -> Explanation {
+        let (count, times) = match &self.op.kind {
+            RepetitionKind::ZeroOrOne => (None, "optionally".to_string()),
+            RepetitionKind::ZeroOrMore => (
+                Some("zero or more".to_string()),
+                "zero or more times".to_string(),
+            ),
+            RepetitionKind::OneOrMore => (
+                Some("one or more".to_string()),
+                "one or more times".to_string(),
+            ),
+            RepetitionKind::Range(RepetitionRange::Exactly(n)) => (
+                Some(format!("exactly {}", n)),
+                format!("exactly {} times", n),
+            ),
+            RepetitionKind::Range(RepetitionRange::AtLeast(n)) => (
+                Some(format!("at least {}", n)),
+                format!("at least {} times", n),
+            ),
+            RepetitionKind::Range(RepetitionRange::Bounded(from, to)) => (
+                Some(format!("{} to {}", from, to)),
+                format!("{} to {} times", from, to),
+            ),
+        };
+        let lazy = if self.greedy {
+            ""
+        } else {
+            " (as few as possible)"
+        };
+        match (self.ast.explain(), count) {
+            (
+                Explanation::Item {
+                    plural: Some(plural),
+                    ..
+                },
+                Some(count),
+            ) => Explanation::item(format!("{} {}{}", count, plural, lazy)),
+            (explanation, _) if !explanation.is_simple() => {
+                Explanation::block(format!("{}{}", times, lazy), explanation)
+            }
+            (Explanation::Item { singular, .. }, None) => {
+                Explanation::item(format!("{} {}{}", times, singular, lazy))
+            }
+            (Explanation::Item { singular, .. }, Some(_)) => {
+                Explanation::item(format!("{}, {}{}", singular, times, lazy))
+            }
+            (explanation, _) => Explanation::block(format!("{}{}", times, lazy), explanation),
+        }
+    }
+}
+
+impl Explainer for Group {
+    fn explain(&self) -> Explanation {
+        let title = match &self.kind {
+            GroupKind::CaptureIndex(index) => format!("captured as group {}", index),
+            GroupKind::CaptureName { name, .. } => format!("captured as {}", name.name),
+            GroupKind::NonCapturing(flags) if flags.items.is_empty() => return self.ast.explain(),
+            GroupKind::NonCapturing(flags) => explain_flags(flags),
+        };
+        match self.ast.explain() {
+            Explanation::Item { singular, .. } => {
+                Explanation::item(format!("{} ({})", singular, title))
+            }
+            explanation => Explanation::block(title, explanation),
+        }
+    }
+}
+
+impl Explainer for Alternation {
+    fn explain(&self) -> Explanation {
+        let parts: Vec<_> = self
+            .asts
+            .iter()
+            .map(|ast| match ast.explain() {
+                // A sequence must be nested so the "or" will not be mixed with the "then".
+                Explanation::Sequence(parts) if parts.len() > 1 => {
+                    Explanation::block("the sequence", Explanation::Sequence(parts))
+                }
+                explanation => explanation,
+            })
+            .collect();
+        let mut singulars = vec![];
+        for part in &parts {
+            match part {
+                Explanation::Item { singular, .. } if part.is_simple() => {
+                    singulars.push(singular.clone())
+                }
+                Explanation::Sequence(parts) if parts.is_empty() => {
+                    singulars.push("nothing".to_string())
+                }
+                _ => {
+                    return Explanation::Block {
+                        title: "one of the following".to_string(),
+                        parts,
+                    }
+                }
+            }
+        }
+        Explanation::item(format!("either {}", singulars.join(" or ")))
+    }
+}
+
+impl Explainer for Concat {
+    fn explain(&self) -> Explanation {
+        let mut parts = vec![];
+        for element in combine_concat(&self.asts) {
+            let explanation = match element {
+                InConcat::Ast(ast) => ast.explain(),
+                InConcat::Char(chr) => Explanation::items(
+                    format!("the character {:?}", chr),
+                    format!("{:?} characters", chr),
+                ),
+                InConcat::Str(str) => Explanation::item(format!("the text {:?}", str)),
+            };
+            match explanation {
+                Explanation::Sequence(inner) => parts.extend(inner),
+                explanation => parts.push(explanation),
+            }
+        }
+        if parts.len() == 1 {
+            return parts.remove(0);
+        }
+        let mut singulars = vec![];
+        for part in &parts {
+            match part {
+                Explanation::Item { singular, .. } if part.is_simple() => {
+                    singulars.push(singular.clone())
+                }
+                _ => return Explanation::Sequence(parts),
+            }
+        }
+        Explanation::item(singulars.join(", then "))
+    }
+}
+
+fn explain_flags(flags: &Flags) -> String {
+    let mut on = true;
+    let mut names = vec![];
+    for item in &flags.items {
+        let flag = match item.kind {
+            FlagsItemKind::Negation => {
+                on = false;
+                continue;
+            }
+            FlagsItemKind::Flag(flag) => flag,
+        };
+        let name = match flag {
+            Flag::CaseInsensitive => "case insensitive",
+            Flag::MultiLine => "multi line",
+            Flag::DotMatchesNewLine => "any character matches a new line",
+            Flag::SwapGreed => "swap greed",
+            Flag::Unicode => "unicode",
+            Flag::CRLF => "CRLF mode",
+            Flag::IgnoreWhitespace => continue,
+        };
+        names.push(if on {
+            name.to_string()
+        } else {
+            format!("not {}", name)
+        });
+    }
+    if names.is_empty() {
+        "no flags".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+fn unicode_class(cls: &ClassUnicode) -> String {
+    match &cls.kind {
+        ClassUnicodeKind::OneLetter(letter) => format!("the unicode class {}", letter),
+        ClassUnicodeKind::Named(name) => format!("the unicode class {}", name),
+        ClassUnicodeKind::NamedValue { op, name, value } => {
+            let op = if *op == ClassUnicodeOpKind::NotEqual {
+                "is not"
+            } else {
+                "is"
+            };
+            format!("characters whose {} {} {}", name, op, value)
+        }
+    }
+}
+
+fn class_set(set: &ClassSet) -> String {
+    match set {
+        ClassSet::Item(item) => class_item(item),
+        ClassSet::BinaryOp(op) => {
+            let lhs = class_set(&op.lhs);
+            let rhs = class_set(&op.rhs);
+            match op.kind {
+                ClassSetBinaryOpKind::Intersection => format!("{} (but only {})", lhs, rhs),
+                ClassSetBinaryOpKind::Difference => format!("{} (except {})", lhs, rhs),
+                ClassSetBinaryOpKind::SymmetricDifference => {
+                    format!("{} or {} (but not both)", lhs, rhs)
+                }
+            }
+        }
+    }
+}
+
+fn class_item(item: &ClassSetItem) -> String {
+    match item {
+        ClassSetItem::Empty(_) => "nothing".to_string(),
+        ClassSetItem::Literal(lit) => class_char(lit.c),
+        ClassSetItem::Range(range) => {
+            format!(
+                "{} to {}",
+                class_char(range.start.c),
+                class_char(range.end.c)
+            )
+        }
+        ClassSetItem::Ascii(cls) => ascii_class(cls),
+        ClassSetItem::Unicode(cls) => {
+            let name = unicode_class(cls);
+            if cls.negated {
+                format!("anything but {}", name)
+            } else {
+                name
+            }
+        }
+        ClassSetItem::Perl(cls) => match cls.explain() {
+            Explanation::Item {
+                plural: Some(plural),
+                ..
+            } => plural,
+            _ => unreachable!(),
+        },
+        ClassSetItem::Bracketed(cls) => {
+            let content = class_set(&cls.kind);
+            if cls.negated {
+                format!("(anything but {})", content)
+            } else {
+                format!("({})", content)
+            }
+        }
+        ClassSetItem::Union(union) => {
+            let items: Vec<_> = union.items.iter().map(class_item).collect();
+            match items.split_last() {
+                None => "nothing".to_string(),
+                Some((last, [])) => last.clone(),
+                Some((last, items)) => format!("{} or {}", items.join(", "), last),
+            }
+        }
+    }
+}
+
+/// A character in a class, letters and digits are not quoted.
+fn class_char(chr: char) -> String {
+    if chr.is_alphanumeric() {
+        chr.to_string()
+    } else {
+        format!("{:?}", chr)
+    }
+}
+
+fn ascii_class(cls: &ClassAscii) -> String {
+    let name = match cls.kind {
+        ClassAsciiKind::Alnum => "ASCII letters and digits",
+        ClassAsciiKind::Alpha => "ASCII letters",
+        ClassAsciiKind::Ascii => "ASCII characters",
+        ClassAsciiKind::Blank => "spaces and tabs",
+        ClassAsciiKind::Cntrl => "ASCII control characters",
+        ClassAsciiKind::Digit => "ASCII digits",
+        ClassAsciiKind::Graph => "visible ASCII characters",
+        ClassAsciiKind::Lower => "ASCII lower case letters",
+        ClassAsciiKind::Print => "printable ASCII characters",
+        ClassAsciiKind::Punct => "ASCII punctuation",
+        ClassAsciiKind::Space => "ASCII white spaces",
+        ClassAsciiKind::Upper => "ASCII upper case letters",
+        ClassAsciiKind::Word => "ASCII word characters",
+        ClassAsciiKind::Xdigit => "hexadecimal digits",
+    };
+    if cls.negated {
+        format!("anything but {}", name)
+    } else {
+        name.to_string()
+    }
+}
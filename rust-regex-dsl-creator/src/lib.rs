@@ -6,10 +6,32 @@
 //! let dsl = "[a-z]+".to_dsl().unwrap();
 //! assert_eq!(dsl, "repeat {\n  any_of {\n    from: 'a', to: 'z',\n  },\n}\n")
 //! ```
+//!
+//! Optional features:
+//! * `sample` - The `Sampler`, to create strings that match (or almost match) a regular expression.
+//! * `migrate` - The `migrate` function, to rewrite `Regex::new("...")` calls to DSL macros.
+//! * `cli` (default) - The command line tool (install it with `cargo install rust-regex-dsl-creator`). Libraries that
+//!   only need the functions should use `default-features = false`.
 
 mod ast_impl;
 mod basic_impls;
+mod error;
+mod explain_impl;
+#[cfg(feature = "migrate")]
+mod migrate;
+#[cfg(feature = "sample")]
+mod near_miss;
 mod printer;
+mod rust_output;
+#[cfg(feature = "sample")]
+mod sample;
+
+pub use error::ToDslError;
+#[cfg(feature = "migrate")]
+pub use migrate::{migrate, AmbiguousCall, Migration};
+pub use rust_output::RustOutput;
+#[cfg(feature = "sample")]
+pub use sample::Sampler;
 
 /// Import this trait to enable the `to_dsl` function for anything that implements the [ToString] trait.
 pub trait ToDsl {
@@ -24,4 +46,58 @@ pub trait ToDsl {
     /// assert_eq!(dsl, "concat {\n  repeat {\n    any_of {\n      from: 'a', to: 'z',\n    },\n  },\n  times {\n    at_least: 2, at_most: 3,\n    any_of {\n      from: '0', to: '9',\n    },\n  },\n  end_of_line,\n}\n")
     /// ```
     fn to_dsl(&self) -> Result<String, regex::Error>;
+
+    /// Like [`ToDsl::to_dsl`], but fails if a part of the regular expression can not be expressed by the DSL
+    /// (instead of falling back to a raw `regex("...")`). For example:
+    /// ```rust
+    /// use rust_regex_dsl_creator::ToDsl;
+    ///
+    /// let dsl = "[[:alpha:]]\\b{end}".to_strict_dsl().unwrap();
+    /// assert_eq!(dsl, "concat {\n  ascii_alpha,\n  word_end,\n}\n");
+    /// assert!("()".to_strict_dsl().is_err());
+    /// ```
+    fn to_strict_dsl(&self) -> Result<String, ToDslError>;
+
+    /// Convert a regular expression to Rust code that uses the DSL (see [`RustOutput`]). This fails for an empty regular
+    /// expression, since it has no DSL. For example:
+    /// ```rust
+    /// use rust_regex_dsl_creator::{RustOutput, ToDsl};
+    ///
+    /// let code = "(?<key>\\w+)=(?<value>\\d+)".to_rust(&RustOutput::CreateCapture(None)).unwrap();
+    /// assert!(code.starts_with("rust_regex_dsl::create_capture!(\n    Key,\n    concat {\n        group {\n"));
+    ///
+    /// let code = "[a-z]+".to_rust(&RustOutput::LazyLock(None)).unwrap();
+    /// assert_eq!(code, "static REGEX: std::sync::LazyLock<rust_regex_dsl::Regex> = std::sync::LazyLock::new(|| {
+    ///     rust_regex_dsl::regex_dsl! {
+    ///         repeat {
+    ///             any_of {
+    ///                 from: 'a', to: 'z',
+    ///             },
+    ///         },
+    ///     }
+    /// });
+    /// ");
+    /// assert!("".to_rust(&RustOutput::RegexDsl).is_err());
+    /// ```
+    fn to_rust(&self, output: &RustOutput) -> Result<String, ToDslError>;
+}
+
+/// Import this trait to enable the `to_explanation` function for anything that implements the [ToString] trait.
+pub trait ToExplanation {
+    /// This function (implemented by default for anything that implement the [ToString] trait) describes a regular
+    /// expression in plain English (parts that can not be described in a single line are indented). For example:
+    /// ```rust
+    /// use rust_regex_dsl_creator::ToExplanation;
+    ///
+    /// let explanation = "[a-z]+[0-9]{2,3}$".to_explanation().unwrap();
+    /// assert_eq!(explanation, "one or more characters from a to z, then 2 to 3 digits, then end of line\n");
+    ///
+    /// let explanation = "(?<user>\\w+)@(foo|bar)\\.com".to_explanation().unwrap();
+    /// assert_eq!(explanation, "one or more word characters (captured as user)
+    /// then the character '@'
+    /// then either the text \"foo\" or the text \"bar\" (captured as group 2)
+    /// then the text \".com\"
+    /// ");
+    /// ```
+    fn to_explanation(&self) -> Result<String, regex::Error>;
 }
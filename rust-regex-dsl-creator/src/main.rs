@@ -1,20 +1,8 @@
-use rust_regex_dsl_creator::ToDsl;
-use std::io::stdin;
-use std::io::BufRead;
-use std::io::Error as IoError;
+use clap::Parser;
+use std::process::ExitCode;
 
-fn main() -> Result<(), IoError> {
-    let stdin = stdin();
-    for line in stdin.lock().lines() {
-        let line = line?;
-        match line.to_dsl() {
-            Ok(dsl) => {
-                println!("For `{}` DSL would look like:\n```\n{}\n```\n", line, dsl);
-            }
-            Err(e) => {
-                eprintln!("Error with `{}` : {}", line, e);
-            }
-        }
-    }
-    Ok(())
+mod cli;
+
+fn main() -> ExitCode {
+    cli::Cli::parse().run()
 }
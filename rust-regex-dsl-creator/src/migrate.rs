@@ -0,0 +1,338 @@
+use std::ops::Range;
+
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprMethodCall, ItemUse, Lit, LitStr, UseTree};
+
+use crate::rust_output::macro_call;
+use crate::{ToDsl, ToDslError};
+
+/// The builder options that the macros support.
+const OPTIONS: [&str; 12] = [
+    "unicode",
+    "case_insensitive",
+    "multi_line",
+    "dot_matches_new_line",
+    "crlf",
+    "swap_greed",
+    "ignore_whitespace",
+    "octal",
+    "size_limit",
+    "dfa_size_limit",
+    "nest_limit",
+    "line_terminator",
+];
+
+/// Options that change the way the regular expression is parsed, so it can not be converted to a DSL.
+const PARSE_OPTIONS: [&str; 2] = ["ignore_whitespace", "octal"];
+
+/// The result of [`migrate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    /// The migrated source.
+    pub source: String,
+    /// The unqualified calls that were not migrated, because the type is not imported from `regex` (or from
+    /// `rust_regex_dsl`) by a `use` item, so it might be another type with the same name.
+    pub ambiguous: Vec<AmbiguousCall>,
+}
+
+/// An unqualified `Regex::new("...")` (or `RegexBuilder::new("...")`) call that was not migrated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguousCall {
+    /// The line of the call (starting from 1).
+    pub line: usize,
+    /// The column of the call (starting from 1).
+    pub column: usize,
+    /// The called function, e.g. `Regex::new`.
+    pub function: String,
+}
+
+/// Rewrite the `Regex::new("...").unwrap()` and `RegexBuilder::new("...")...build().unwrap()` calls in a Rust source
+/// (with a literal regular expression) to `regex_dsl! { ... }` (or to `regex!("...")` if the regular expression can
+/// not be converted to a DSL). Calls with an invalid regular expression are left as is.
+///
+/// Unqualified calls are migrated only if the type is imported from `regex` or `rust_regex_dsl` (e.g. with
+/// `use regex::Regex;`), other unqualified `Regex::new` and `RegexBuilder::new` calls are reported in
+/// [`Migration::ambiguous`].
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl_creator::migrate;
+///
+/// let source = "use regex::Regex;\n\nfn digits() -> Regex {\n    Regex::new(\"\\\\d+\").unwrap()\n}\n";
+/// assert_eq!(
+///     migrate(source).unwrap().source,
+///     "use regex::Regex;\n\nfn digits() -> Regex {\n    rust_regex_dsl::regex_dsl! {\n        repeat {\n            digit,\n        },\n    }\n}\n"
+/// );
+///
+/// let migration = migrate("fn digits() -> Regex {\n    Regex::new(\"\\\\d+\").unwrap()\n}\n").unwrap();
+/// assert_eq!(migration.ambiguous[0].line, 2);
+/// ```
+pub fn migrate(source: &str) -> syn::Result<Migration> {
+    let file = syn::parse_file(source)?;
+    let mut imports = Imports::default();
+    imports.visit_file(&file);
+    let mut migrator = Migrator {
+        source,
+        imports,
+        replacements: vec![],
+        ambiguous: vec![],
+    };
+    migrator.visit_file(&file);
+    let mut migrated = source.to_string();
+    for (range, replacement) in migrator.replacements.into_iter().rev() {
+        migrated.replace_range(range, &replacement);
+    }
+    Ok(Migration {
+        source: migrated,
+        ambiguous: migrator.ambiguous,
+    })
+}
+
+struct Migrator<'s> {
+    source: &'s str,
+    imports: Imports,
+    replacements: Vec<(Range<usize>, String)>,
+    ambiguous: Vec<AmbiguousCall>,
+}
+
+/// The names that the `use` items of a file import, with their paths (the scope of the items is ignored).
+#[derive(Default)]
+struct Imports {
+    names: Vec<(String, Vec<String>)>,
+}
+
+/// The type that a call creates.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RegexType {
+    Str,
+    Bytes,
+    /// An unqualified name that is not imported from `regex` (or from `rust_regex_dsl`).
+    Ambiguous,
+}
+
+struct RegexCall<'a> {
+    regex: &'a LitStr,
+    regex_type: RegexType,
+    /// The called function, e.g. `Regex::new`.
+    function: String,
+    options: Vec<(String, String)>,
+}
+
+impl<'ast> Visit<'ast> for Imports {
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        self.add(&item.tree, vec![]);
+    }
+}
+
+impl Imports {
+    fn add(&mut self, tree: &UseTree, mut path: Vec<String>) {
+        match tree {
+            UseTree::Path(tree) => {
+                path.push(tree.ident.to_string());
+                self.add(&tree.tree, path);
+            }
+            UseTree::Name(name) => {
+                path.push(name.ident.to_string());
+                self.names.push((name.ident.to_string(), path));
+            }
+            UseTree::Rename(rename) => {
+                path.push(rename.ident.to_string());
+                self.names.push((rename.rename.to_string(), path));
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.add(tree, path.clone());
+                }
+            }
+            UseTree::Glob(_) => {}
+        }
+    }
+
+    /// The type of an unqualified name, or `None` if it's imported from somewhere else.
+    fn resolve(&self, name: &str, type_name: &str) -> Option<RegexType> {
+        let mut types = self
+            .names
+            .iter()
+            .filter(|(imported, _)| imported == name)
+            .map(|(_, path)| {
+                let path: Vec<_> = path.iter().map(String::as_str).collect();
+                regex_type(&path, type_name)
+            });
+        let Some(first) = types.next() else {
+            // Without an import, this might be the type from a glob import or a local type.
+            return (name == type_name).then_some(RegexType::Ambiguous);
+        };
+        if types.all(|other| other == first) {
+            first
+        } else {
+            Some(RegexType::Ambiguous)
+        }
+    }
+}
+
+/// The type of a `regex::<type_name>`, `regex::bytes::<type_name>` (or the same types from `rust_regex_dsl`) path.
+fn regex_type(path: &[&str], type_name: &str) -> Option<RegexType> {
+    match path {
+        ["regex" | "rust_regex_dsl", name] if *name == type_name => Some(RegexType::Str),
+        ["regex" | "rust_regex_dsl", "bytes", name] if *name == type_name => Some(RegexType::Bytes),
+        _ => None,
+    }
+}
+
+impl<'ast> Visit<'ast> for Migrator<'_> {
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        match self.replacement(call) {
+            Some(replacement) => self
+                .replacements
+                .push((call.span().byte_range(), replacement)),
+            None => visit::visit_expr_method_call(self, call),
+        }
+    }
+}
+
+impl Migrator<'_> {
+    fn replacement(&mut self, call: &ExprMethodCall) -> Option<String> {
+        let regex_call = match (call.method.to_string().as_str(), call.args.len()) {
+            ("unwrap", 0) | ("expect", 1) => RegexCall::parse(&call.receiver, &self.imports)?,
+            _ => return None,
+        };
+        let regex = regex_call.regex.value();
+        if regex_call.regex_type == RegexType::Ambiguous {
+            // Only calls that would have been migrated are interesting.
+            if regex::bytes::Regex::new(&regex).is_ok() {
+                let start = call.span().start();
+                self.ambiguous.push(AmbiguousCall {
+                    line: start.line,
+                    column: start.column + 1,
+                    function: regex_call.function,
+                });
+            }
+            return None;
+        }
+        let bytes = regex_call.regex_type == RegexType::Bytes;
+        let can_convert = !regex_call
+            .options
+            .iter()
+            .any(|(name, _)| PARSE_OPTIONS.contains(&name.as_str()));
+        let dsl = match regex.to_strict_dsl() {
+            Ok(dsl) if can_convert => Some(dsl),
+            Ok(_) | Err(ToDslError::Unsupported(_)) => None,
+            // A bytes regular expression that is not valid for a string can only be used as is.
+            Err(ToDslError::InvalidRegex(_))
+                if bytes && regex::bytes::Regex::new(&regex).is_ok() =>
+            {
+                None
+            }
+            Err(ToDslError::InvalidRegex(_)) => return None,
+        };
+        let options: Vec<_> = regex_call
+            .options
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect();
+        let replacement = match dsl {
+            Some(dsl) => {
+                let name = if bytes {
+                    "regex_dsl_bytes"
+                } else {
+                    "regex_dsl"
+                };
+                macro_call(name, &dsl, &options, 0)
+                    .trim_end()
+                    .replace('\n', &format!("\n{}", self.indentation(call)))
+            }
+            None => {
+                let name = if bytes { "regex_bytes" } else { "regex" };
+                let literal = &self.source[regex_call.regex.span().byte_range()];
+                let arguments: Vec<_> = [literal.to_string()].into_iter().chain(options).collect();
+                format!("rust_regex_dsl::{}!({})", name, arguments.join(", "))
+            }
+        };
+        Some(replacement)
+    }
+
+    /// The white spaces in the beginning of the line of the call.
+    fn indentation(&self, call: &ExprMethodCall) -> &str {
+        let start = call.span().byte_range().start;
+        let line_start = self.source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line = &self.source[line_start..start];
+        &line[..line.len() - line.trim_start().len()]
+    }
+}
+
+impl<'a> RegexCall<'a> {
+    /// `Regex::new("...")` or `RegexBuilder::new("...").option(value)...build()`.
+    fn parse(expr: &'a Expr, imports: &Imports) -> Option<Self> {
+        match expr {
+            Expr::Call(_) => RegexCall::parse_new(expr, "Regex", imports),
+            Expr::MethodCall(build) if build.method == "build" && build.args.is_empty() => {
+                let mut options = vec![];
+                let mut receiver = &*build.receiver;
+                while let Expr::MethodCall(option) = receiver {
+                    let name = option.method.to_string();
+                    if !OPTIONS.contains(&name.as_str()) || option.args.len() != 1 {
+                        return None;
+                    }
+                    let Expr::Lit(value) = &option.args[0] else {
+                        return None;
+                    };
+                    let value = match &value.lit {
+                        Lit::Bool(value) => value.value.to_string(),
+                        Lit::Int(value) => value.base10_digits().to_string(),
+                        Lit::Byte(value) => value.value().to_string(),
+                        _ => return None,
+                    };
+                    if options.iter().any(|(n, _)| *n == name) {
+                        return None;
+                    }
+                    options.push((name, value));
+                    receiver = &option.receiver;
+                }
+                let mut regex_call = RegexCall::parse_new(receiver, "RegexBuilder", imports)?;
+                options.reverse();
+                regex_call.options = options;
+                Some(regex_call)
+            }
+            _ => None,
+        }
+    }
+
+    /// `regex::<type_name>::new("...")` or `regex::bytes::<type_name>::new("...")` (or the same types from
+    /// `rust_regex_dsl`), or an unqualified `<name>::new("...")` that is resolved with the imports.
+    fn parse_new(expr: &'a Expr, type_name: &str, imports: &Imports) -> Option<Self> {
+        let Expr::Call(call) = expr else {
+            return None;
+        };
+        let Expr::Path(func) = &*call.func else {
+            return None;
+        };
+        let segments: Vec<_> = func
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let segments: Vec<_> = segments.iter().map(String::as_str).collect();
+        let regex_type = match segments.as_slice() {
+            [name, "new"] => imports.resolve(name, type_name)?,
+            [path @ .., "new"] => regex_type(path, type_name)?,
+            _ => return None,
+        };
+        if call.args.len() != 1 {
+            return None;
+        }
+        let Expr::Lit(regex) = &call.args[0] else {
+            return None;
+        };
+        let Lit::Str(regex) = &regex.lit else {
+            return None;
+        };
+        Some(RegexCall {
+            regex,
+            regex_type,
+            function: segments.join("::"),
+            options: vec![],
+        })
+    }
+}
@@ -0,0 +1,168 @@
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use crate::Sampler;
+
+/// The characters to try (in order) when a character that does not match is needed.
+const REPLACEMENTS: &str = "a0A_-. !#";
+
+/// Repetitions with more than that can not be exceeded (the sample would be too long).
+const MAX_EXCEEDED_REPEAT: u32 = 100;
+
+/// Samples with a single mutation, generated lazily (so the search can stop once there are enough near misses).
+type Mutations<'a> = Box<dyn Iterator<Item = Vec<u8>> + 'a>;
+
+impl Sampler {
+    /// Up to `limit` distinct strings that almost match the regular expression: every one of them is a valid sample with
+    /// a single mutation (a dropped literal or character, a character that is not part of a class, or too few or too
+    /// many repetitions), that the regular expression does not match.
+    ///
+    /// The candidates are checked with the regular expression as it is (like `is_match` would), so anchoring matters:
+    /// without `^` and `$`, a candidate that contains a match (like `aaaa` for `[a-z]{2,3}`) is not a near miss.
+    /// For example:
+    /// ```rust
+    /// use rust_regex_dsl_creator::Sampler;
+    ///
+    /// let sampler = Sampler::new("^[a-z]{2,3}-[0-9]$").unwrap();
+    /// assert_eq!(
+    ///     sampler.near_misses(10),
+    ///     vec!["a-0", "aaaa-0", "0a-0", "aa0", "aaa0", "aa-", "aa-a"]
+    /// );
+    ///
+    /// let sampler = Sampler::new("[a-z]{2,3}").unwrap();
+    /// assert_eq!(sampler.near_misses(10), vec!["a", "0a"]);
+    /// ```
+    pub fn near_misses(&self, limit: usize) -> Vec<String> {
+        self.near_misses_bytes(limit)
+            .into_iter()
+            .filter_map(|sample| String::from_utf8(sample).ok())
+            .collect()
+    }
+
+    /// Like [`Sampler::near_misses`], but the results can be any bytes (for a sampler created with
+    /// [`Sampler::new_bytes`]).
+    pub fn near_misses_bytes(&self, limit: usize) -> Vec<Vec<u8>> {
+        let mut samples: Vec<Vec<u8>> = vec![];
+        if limit == 0 {
+            return samples;
+        }
+        for sample in self.mutations(&self.hir) {
+            if !self.regex.is_match(&sample) && !samples.contains(&sample) {
+                samples.push(sample);
+                if samples.len() >= limit {
+                    break;
+                }
+            }
+        }
+        samples
+    }
+
+    /// The first sample of a part of the regular expression.
+    fn base(&self, hir: &Hir) -> Option<Vec<u8>> {
+        self.all(hir, 1).into_iter().next()
+    }
+
+    /// Samples of a part of the regular expression with exactly one mutation.
+    fn mutations<'a>(&'a self, hir: &'a Hir) -> Mutations<'a> {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => Box::new(std::iter::empty()),
+            HirKind::Literal(literal) => {
+                let literal = &literal.0;
+                let mut mutations = vec![vec![]];
+                let first_length = match std::str::from_utf8(literal) {
+                    Ok(str) => str.chars().next().map_or(1, char::len_utf8),
+                    Err(_) => 1,
+                };
+                if literal.len() > first_length {
+                    mutations.push(literal[first_length..].to_vec());
+                }
+                if let Some(replacement) = REPLACEMENTS
+                    .bytes()
+                    .find(|replacement| !literal.starts_with(&[*replacement]))
+                {
+                    mutations.push([&[replacement], &literal[first_length..]].concat());
+                }
+                Box::new(mutations.into_iter())
+            }
+            HirKind::Class(class) => {
+                let mut mutations = vec![vec![]];
+                if let Some(outside) = outside(class) {
+                    mutations.push(outside);
+                }
+                Box::new(mutations.into_iter())
+            }
+            HirKind::Repetition(repetition) => {
+                let Some(base) = self.base(&repetition.sub) else {
+                    return Box::new(std::iter::empty());
+                };
+                let min = repetition.min;
+                let mut mutations = vec![];
+                if min > 0 {
+                    mutations.push(base.repeat(min as usize - 1));
+                }
+                if let Some(max) = repetition.max.filter(|max| *max < MAX_EXCEEDED_REPEAT) {
+                    mutations.push(base.repeat(max as usize + 1));
+                }
+                let others = base.repeat(min.max(1) as usize - 1);
+                let sub_mutations = self
+                    .mutations(&repetition.sub)
+                    .map(move |mutation| [mutation, others.clone()].concat());
+                Box::new(mutations.into_iter().chain(sub_mutations))
+            }
+            HirKind::Capture(capture) => self.mutations(&capture.sub),
+            HirKind::Concat(hirs) => {
+                let Some(bases) = hirs
+                    .iter()
+                    .map(|hir| self.base(hir))
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return Box::new(std::iter::empty());
+                };
+                Box::new(hirs.iter().enumerate().flat_map(move |(index, hir)| {
+                    let before = bases[..index].concat();
+                    let after = bases[index + 1..].concat();
+                    self.mutations(hir)
+                        .map(move |mutation| [&before[..], &mutation, &after].concat())
+                }))
+            }
+            HirKind::Alternation(hirs) => Box::new(hirs.iter().flat_map(|hir| self.mutations(hir))),
+        }
+    }
+}
+
+/// A character that is not part of the class (a readable one if possible).
+fn outside(class: &Class) -> Option<Vec<u8>> {
+    match class {
+        Class::Unicode(class) => {
+            let mut outside = class.clone();
+            outside.negate();
+            let contains = |chr: char| {
+                outside
+                    .ranges()
+                    .iter()
+                    .any(|range| range.start() <= chr && chr <= range.end())
+            };
+            let chr = REPLACEMENTS
+                .chars()
+                .chain('!'..='~')
+                .find(|chr| contains(*chr))
+                .or_else(|| outside.ranges().first().map(|range| range.start()))?;
+            Some(chr.to_string().into_bytes())
+        }
+        Class::Bytes(class) => {
+            let mut outside = class.clone();
+            outside.negate();
+            let contains = |byte: u8| {
+                outside
+                    .ranges()
+                    .iter()
+                    .any(|range| range.start() <= byte && byte <= range.end())
+            };
+            let byte = REPLACEMENTS
+                .bytes()
+                .chain(b'!'..=b'~')
+                .find(|byte| contains(*byte))
+                .or_else(|| outside.ranges().first().map(|range| range.start()))?;
+            Some(vec![byte])
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::Display;
 #[derive(Default)]
 pub struct Printer {
     out: Vec<String>,
+    fallbacks: Vec<String>,
 }
 
 impl Printer {
@@ -19,10 +20,28 @@ impl Printer {
             let line = format!("{}{}{}", indent, line, comma);
             self.println(line);
         }
+        self.fallbacks.extend(indented.fallbacks.iter().cloned());
+    }
+    /// Add all the lines of the other printer without indentation.
+    pub fn append_raw(&mut self, other: &Self) {
+        self.out.extend(other.out.iter().cloned());
+        self.fallbacks.extend(other.fallbacks.iter().cloned());
     }
     pub fn println(&mut self, line: String) {
         self.out.push(line);
     }
+    /// Print a raw regular expression, for something that has no DSL equivalent.
+    pub fn fallback(&mut self, line: String, regex: String) {
+        self.println(line);
+        self.fallbacks.push(regex);
+    }
+    /// The regular expressions that were printed as raw regular expressions.
+    pub fn fallbacks(&self) -> &[String] {
+        &self.fallbacks
+    }
+    pub fn is_empty(&self) -> bool {
+        self.out.is_empty()
+    }
 }
 
 impl Display for Printer {
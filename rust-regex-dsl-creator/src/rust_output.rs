@@ -0,0 +1,118 @@
+/// The Rust code to create with [`ToDsl::to_rust`](crate::ToDsl::to_rust).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RustOutput {
+    /// A `regex_dsl! { ... }` invocation.
+    RegexDsl,
+    /// A `create_capture!(Name, ...)` invocation. If the name is missing, it's inferred from the first named capture
+    /// group.
+    CreateCapture(Option<String>),
+    /// A `static NAME: LazyLock<Regex>` with a `regex_dsl!` invocation. If the name is missing, it's inferred from the first
+    /// named capture group.
+    LazyLock(Option<String>),
+}
+
+const INDENT: &str = "    ";
+
+impl RustOutput {
+    pub(crate) fn print(&self, dsl: &str, capture_names: &[&str]) -> String {
+        match self {
+            RustOutput::RegexDsl => regex_dsl(dsl, 0),
+            RustOutput::CreateCapture(name) => {
+                let name = match name {
+                    Some(name) => name.clone(),
+                    None => struct_name(capture_names),
+                };
+                format!(
+                    "rust_regex_dsl::create_capture!(\n{}{},\n{});\n",
+                    INDENT,
+                    name,
+                    indent(dsl, 1)
+                )
+            }
+            RustOutput::LazyLock(name) => {
+                let name = match name {
+                    Some(name) => name.clone(),
+                    None => static_name(capture_names),
+                };
+                format!(
+                    "static {}: std::sync::LazyLock<rust_regex_dsl::Regex> = std::sync::LazyLock::new(|| {{\n{}}});\n",
+                    name,
+                    regex_dsl(dsl, 1)
+                )
+            }
+        }
+    }
+}
+
+fn regex_dsl(dsl: &str, level: usize) -> String {
+    macro_call("regex_dsl", dsl, &[], level)
+}
+
+/// A DSL macro invocation (i.e. `rust_regex_dsl::<name>! { <dsl>, <options> }`).
+pub(crate) fn macro_call(name: &str, dsl: &str, options: &[String], level: usize) -> String {
+    let prefix = INDENT.repeat(level);
+    let options: String = options
+        .iter()
+        .map(|option| format!("{}{}{},\n", prefix, INDENT, option))
+        .collect();
+    format!(
+        "{}rust_regex_dsl::{}! {{\n{}{}{}}}\n",
+        prefix,
+        name,
+        indent(dsl, level + 1),
+        options,
+        prefix
+    )
+}
+
+/// Indent the DSL lines (which are indented by two spaces) with `INDENT`, and end the top level elements with a comma.
+fn indent(dsl: &str, level: usize) -> String {
+    dsl.lines()
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let depth = (line.len() - content.len()) / 2;
+            let comma = match content.chars().last() {
+                _ if depth > 0 => "",
+                Some(',') | Some('{') => "",
+                _ => ",",
+            };
+            format!("{}{}{}\n", INDENT.repeat(depth + level), content, comma)
+        })
+        .collect()
+}
+
+/// The words of the first capture name (the other names are ignored, joining them might produce an ambiguous name).
+fn words<'a>(capture_names: &'a [&str]) -> impl Iterator<Item = &'a str> {
+    capture_names
+        .first()
+        .into_iter()
+        .flat_map(|name| name.split(|c: char| !c.is_ascii_alphanumeric()))
+        .filter(|word| !word.is_empty())
+}
+
+/// The first capture name in upper camel case (i.e. `first_name` is `FirstName`).
+fn struct_name(capture_names: &[&str]) -> String {
+    let name: String = words(capture_names)
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if name.is_empty() {
+        "Capture".to_string()
+    } else {
+        name
+    }
+}
+
+/// The first capture name in upper snake case (i.e. `first_name` is `FIRST_NAME_REGEX`).
+fn static_name(capture_names: &[&str]) -> String {
+    words(capture_names)
+        .map(|word| word.to_ascii_uppercase())
+        .chain(["REGEX".to_string()])
+        .collect::<Vec<_>>()
+        .join("_")
+}
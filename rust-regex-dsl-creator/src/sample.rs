@@ -0,0 +1,265 @@
+use rand::Rng;
+use regex::bytes::Regex;
+use regex_syntax::hir::{Class, Hir, HirKind, Look};
+use regex_syntax::ParserBuilder;
+
+/// The number of extra repetitions that an unbounded repetition can have (i.e. `a+` is one to five `a`s).
+const DEFAULT_MAX_REPEAT: u32 = 4;
+
+/// How many random candidates to try before giving up (a candidate might fail an assertion, like `\b`).
+const ATTEMPTS: usize = 100;
+
+/// Generate strings that match a regular expression, either randomly or by enumerating them.
+///
+/// The repetitions are bounded (see [`Sampler::with_max_repeat`]), and every generated string is verified to match
+/// the entire regular expression (so assertions, like `\b` or `$`, are respected). For example:
+/// ```rust
+/// use rust_regex_dsl_creator::Sampler;
+///
+/// let sampler = Sampler::new("[a-c]{2}-[0-9]+").unwrap();
+/// let sample = sampler.sample(&mut rand::rng()).unwrap();
+/// assert!(regex::Regex::new("^[a-c]{2}-[0-9]+$").unwrap().is_match(&sample));
+///
+/// assert_eq!(sampler.enumerate(3), vec!["aa-0", "aa-1", "aa-2"]);
+/// ```
+pub struct Sampler {
+    pub(crate) hir: Hir,
+    /// The regular expression as it was compiled by the user (i.e. not anchored).
+    pub(crate) regex: Regex,
+    verifier: Regex,
+    max_repeat: u32,
+}
+
+impl Sampler {
+    /// Create a sampler for a regular expression (as used by [`regex::Regex`]).
+    pub fn new(regex: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(regex)?;
+        Self::build(regex, true)
+    }
+
+    /// Create a sampler for a bytes regular expression (as used by [`regex::bytes::Regex`]).
+    pub fn new_bytes(regex: &str) -> Result<Self, regex::Error> {
+        Self::build(regex, false)
+    }
+
+    fn build(regex: &str, utf8: bool) -> Result<Self, regex::Error> {
+        let compiled = Regex::new(regex)?;
+        let mut parser = ParserBuilder::new().utf8(utf8).build();
+        // This must pass since the regular expression is valid
+        let hir = parser.parse(regex).unwrap();
+        let anchored = Hir::concat(vec![
+            Hir::look(Look::Start),
+            hir.clone(),
+            Hir::look(Look::End),
+        ]);
+        Ok(Sampler {
+            hir,
+            regex: compiled,
+            verifier: Regex::new(&anchored.to_string())?,
+            max_repeat: DEFAULT_MAX_REPEAT,
+        })
+    }
+
+    /// Set the maximal number of extra repetitions (on top of the minimum) that a repetition can have.
+    pub fn with_max_repeat(mut self, max_repeat: u32) -> Self {
+        self.max_repeat = max_repeat;
+        self
+    }
+
+    /// A random string that matches the regular expression, or `None` if none was found.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<String> {
+        (0..ATTEMPTS).find_map(|_| {
+            let sample = self.random(&self.hir, rng)?;
+            if self.verifier.is_match(&sample) {
+                String::from_utf8(sample).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Like [`Sampler::sample`], but the result can be any bytes (for a sampler created with [`Sampler::new_bytes`]).
+    pub fn sample_bytes<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Vec<u8>> {
+        (0..ATTEMPTS).find_map(|_| {
+            let sample = self.random(&self.hir, rng)?;
+            self.verifier.is_match(&sample).then_some(sample)
+        })
+    }
+
+    /// Up to `limit` distinct strings that match the regular expression, in order (i.e. `[ab][0-9]` starts with
+    /// `a0`, `a1`...).
+    pub fn enumerate(&self, limit: usize) -> Vec<String> {
+        self.enumerate_bytes(limit)
+            .into_iter()
+            .filter_map(|sample| String::from_utf8(sample).ok())
+            .collect()
+    }
+
+    /// Like [`Sampler::enumerate`], but the results can be any bytes (for a sampler created with [`Sampler::new_bytes`]).
+    pub fn enumerate_bytes(&self, limit: usize) -> Vec<Vec<u8>> {
+        let mut samples: Vec<Vec<u8>> = vec![];
+        for sample in self.all(&self.hir, limit) {
+            if self.verifier.is_match(&sample) && !samples.contains(&sample) {
+                samples.push(sample);
+            }
+        }
+        samples
+    }
+
+    /// The minimal and maximal number of repetitions.
+    fn bounds(&self, min: u32, max: Option<u32>) -> (u32, u32) {
+        let max = min
+            .saturating_add(self.max_repeat)
+            .min(max.unwrap_or(u32::MAX));
+        (min, max)
+    }
+
+    fn random<R: Rng + ?Sized>(&self, hir: &Hir, rng: &mut R) -> Option<Vec<u8>> {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => Some(vec![]),
+            HirKind::Literal(literal) => Some(literal.0.to_vec()),
+            HirKind::Class(Class::Unicode(class)) => {
+                let ranges: Vec<_> = class
+                    .ranges()
+                    .iter()
+                    .map(|range| (range.start() as u32, range.end() as u32))
+                    .collect();
+                // Prefer ASCII characters, they make more readable samples.
+                let ascii = ascii_ranges(&ranges);
+                let ranges = if !ascii.is_empty() && rng.random_ratio(3, 4) {
+                    ascii
+                } else {
+                    ranges
+                };
+                loop {
+                    let chr = char::from_u32(random_in(&ranges, rng)?);
+                    if let Some(chr) = chr {
+                        return Some(chr.to_string().into_bytes());
+                    }
+                }
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                let ranges: Vec<_> = class
+                    .ranges()
+                    .iter()
+                    .map(|range| (range.start() as u32, range.end() as u32))
+                    .collect();
+                Some(vec![random_in(&ranges, rng)? as u8])
+            }
+            HirKind::Repetition(repetition) => {
+                let (min, max) = self.bounds(repetition.min, repetition.max);
+                let mut sample = vec![];
+                for _ in 0..rng.random_range(min..=max) {
+                    sample.extend(self.random(&repetition.sub, rng)?);
+                }
+                Some(sample)
+            }
+            HirKind::Capture(capture) => self.random(&capture.sub, rng),
+            HirKind::Concat(hirs) => {
+                let mut sample = vec![];
+                for hir in hirs {
+                    sample.extend(self.random(hir, rng)?);
+                }
+                Some(sample)
+            }
+            HirKind::Alternation(hirs) => {
+                let hir = &hirs[rng.random_range(0..hirs.len())];
+                self.random(hir, rng)
+            }
+        }
+    }
+
+    pub(crate) fn all(&self, hir: &Hir, limit: usize) -> Vec<Vec<u8>> {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => vec![vec![]],
+            HirKind::Literal(literal) => vec![literal.0.to_vec()],
+            HirKind::Class(Class::Unicode(class)) => class
+                .ranges()
+                .iter()
+                .flat_map(|range| range.start()..=range.end())
+                .take(limit)
+                .map(|chr| chr.to_string().into_bytes())
+                .collect(),
+            HirKind::Class(Class::Bytes(class)) => class
+                .ranges()
+                .iter()
+                .flat_map(|range| range.start()..=range.end())
+                .take(limit)
+                .map(|byte| vec![byte])
+                .collect(),
+            HirKind::Repetition(repetition) => {
+                let (min, max) = self.bounds(repetition.min, repetition.max);
+                let sub = self.all(&repetition.sub, limit);
+                let mut samples = vec![];
+                for count in min..=max {
+                    let mut repeated = vec![vec![]];
+                    for _ in 0..count {
+                        repeated = product(&repeated, &sub, limit);
+                    }
+                    samples.extend(repeated);
+                    if samples.len() >= limit || sub.is_empty() {
+                        break;
+                    }
+                }
+                samples.truncate(limit);
+                samples
+            }
+            HirKind::Capture(capture) => self.all(&capture.sub, limit),
+            HirKind::Concat(hirs) => hirs.iter().fold(vec![vec![]], |samples, hir| {
+                product(&samples, &self.all(hir, limit), limit)
+            }),
+            HirKind::Alternation(hirs) => {
+                // Take one from every alternative in turn, so all of them will be in the result.
+                let alternatives: Vec<_> = hirs.iter().map(|hir| self.all(hir, limit)).collect();
+                let longest = alternatives.iter().map(Vec::len).max().unwrap_or(0);
+                (0..longest)
+                    .flat_map(|index| alternatives.iter().filter_map(move |all| all.get(index)))
+                    .take(limit)
+                    .cloned()
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Every sample of the first list followed by every sample of the second list (up to the limit).
+fn product(first: &[Vec<u8>], second: &[Vec<u8>], limit: usize) -> Vec<Vec<u8>> {
+    first
+        .iter()
+        .flat_map(|first| {
+            second
+                .iter()
+                .map(move |second| [&first[..], second].concat())
+        })
+        .take(limit)
+        .collect()
+}
+
+/// The ASCII parts of the (inclusive) ranges.
+fn ascii_ranges(ranges: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    ranges
+        .iter()
+        .filter(|(start, _)| *start < 0x80)
+        .map(|(start, end)| (*start, (*end).min(0x7F)))
+        .collect()
+}
+
+/// A random number from one of the (inclusive) ranges, or `None` if there are no ranges.
+fn random_in<R: Rng + ?Sized>(ranges: &[(u32, u32)], rng: &mut R) -> Option<u32> {
+    let size: u64 = ranges
+        .iter()
+        .map(|(start, end)| (end - start) as u64 + 1)
+        .sum();
+    if size == 0 {
+        return None;
+    }
+    let mut index = rng.random_range(0..size);
+    for (start, end) in ranges {
+        let length = (end - start) as u64 + 1;
+        if index < length {
+            return Some(start + index as u32);
+        }
+        index -= length;
+    }
+    None
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use regex::Regex;
-use rust_regex_dsl_creator::ToDsl;
+use rust_regex_dsl_creator::{RustOutput, ToDsl};
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
@@ -53,6 +53,12 @@ impl TestSuite {
         env::set_current_dir(dir)?;
 
         let vec = self.tests.iter().map(|t| t.prepare()).join(",\n");
+        let items = self
+            .tests
+            .iter()
+            .enumerate()
+            .map(|(i, t)| t.prepare_items(i))
+            .join("\n");
 
         let ec = Command::new("cargo")
             .arg("init")
@@ -80,6 +86,9 @@ impl TestSuite {
 use std::{collections::HashMap, env};
 
 use rust_regex_dsl::regex_dsl;
+
+<items>
+
 fn main() {
 
     let regexes = vec![
@@ -119,7 +128,8 @@ fn main() {
 }
         
         "#
-        .replace("<to_replace>", &vec);
+        .replace("<to_replace>", &vec)
+        .replace("<items>", &items);
         let main_rs_file = dir.join("src").join("main.rs");
         fs::write(main_rs_file, main_rs)?;
 
@@ -148,12 +158,30 @@ impl Test {
         println!("OK");
     }
     fn prepare(&self) -> String {
-        let dsl = self.regex.to_dsl().unwrap();
+        let dsl = self.regex.to_strict_dsl().unwrap();
         println!(
             "For {} which is {} the DSL would look like:\n{}",
             self.name, self.regex, dsl
         );
-        format!("(\"{}\", regex_dsl! {{\n{}\n}})", self.name, dsl)
+        let code = self.regex.to_rust(&RustOutput::RegexDsl).unwrap();
+        format!("(\"{}\", {})", self.name, code)
+    }
+    fn prepare_items(&self, index: usize) -> String {
+        let capture = self
+            .regex
+            .to_rust(&RustOutput::CreateCapture(Some(format!(
+                "Capture{}",
+                index
+            ))))
+            .unwrap();
+        let lazy_lock = self
+            .regex
+            .to_rust(&RustOutput::LazyLock(Some(format!("REGEX_{}", index))))
+            .unwrap();
+        format!(
+            "#[allow(dead_code)]\nmod capture_{} {{\n{}\n{}\n}}",
+            index, capture, lazy_lock
+        )
     }
 
     fn test(&self, suite: &TestSuite) -> Result<()> {
@@ -0,0 +1,207 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use tempdir::TempDir;
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust-regex-dsl-creator"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn convert_from_arguments() {
+    let output = run(&["convert", "-o", "plain", "a+"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "repeat {\n  eq(\"a\"),\n}\n\n");
+}
+
+#[test]
+fn convert_from_stdin_as_markdown() {
+    let output = run(&["convert"], "\\d\n");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "For `\\d` DSL would look like:\n```\ndigit\n\n```\n\n"
+    );
+}
+
+#[test]
+fn convert_without_a_command() {
+    let output = run(&[], "\\d\n");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "For `\\d` DSL would look like:\n```\ndigit\n\n```\n\n"
+    );
+}
+
+#[test]
+fn convert_a_dsl() {
+    let output = run(
+        &[
+            "convert",
+            "--dsl",
+            "-o",
+            "plain",
+            "concat { \"a\", maybe { digit } }",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "concat {\n  eq(\"a\"),\n  maybe {\n    digit,\n  },\n}\n\n"
+    );
+}
+
+#[test]
+fn convert_from_file_as_json() {
+    let dir = TempDir::new("cli").unwrap();
+    let file = dir.path().join("regexes.txt");
+    fs::write(&file, "a\n(\n").unwrap();
+    let output = run(&["convert", "-o", "json", "-f", file.to_str().unwrap()], "");
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(json[0]["input"], "a");
+    assert_eq!(json[0]["output"], "eq(\"a\")\n");
+    assert_eq!(json[1]["input"], "(");
+    assert!(json[1]["error"].is_string());
+}
+
+#[test]
+fn convert_to_rust() {
+    let output = run(
+        &[
+            "convert",
+            "-o",
+            "rust",
+            "--macro",
+            "create-capture",
+            "(?<a>a)",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("rust_regex_dsl::create_capture!(\n    A,\n"));
+}
+
+#[test]
+fn strict_convert_fails() {
+    let output = run(&["convert", "--strict", "()"], "");
+    assert!(!output.status.success());
+    let output = run(&["convert", "()"], "");
+    assert!(output.status.success());
+}
+
+#[test]
+fn check() {
+    let dir = TempDir::new("cli").unwrap();
+    let good = dir.path().join("good.dsl");
+    let bad = dir.path().join("bad.dsl");
+    fs::write(&good, "repeat { digit }").unwrap();
+    fs::write(&bad, "concat {\n  no_such_word\n}").unwrap();
+
+    let output = run(&["check", good.to_str().unwrap()], "");
+    assert!(output.status.success());
+
+    let output = run(
+        &["check", good.to_str().unwrap(), bad.to_str().unwrap()],
+        "",
+    );
+    assert!(!output.status.success());
+    let error = String::from_utf8(output.stderr).unwrap();
+    assert!(error.ends_with("bad.dsl: 2:3: Unknown word: no_such_word\n"));
+
+    let output = run(&["check"], "any_of { 'a' }");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "<stdin>: OK\n");
+}
+
+#[test]
+fn migrate() {
+    let dir = TempDir::new("cli").unwrap();
+    let file = dir.path().join("main.rs");
+    let source =
+        "use regex::Regex;\n\nfn main() {\n    let regex = Regex::new(\"a\").unwrap();\n}\n";
+    fs::write(&file, source).unwrap();
+
+    let output = run(&["migrate", "--dry-run", dir.path().to_str().unwrap()], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("+    let regex = rust_regex_dsl::regex_dsl! {\n"));
+    assert_eq!(fs::read_to_string(&file).unwrap(), source);
+
+    let output = run(&["migrate", file.to_str().unwrap()], "");
+    assert!(output.status.success());
+    assert!(fs::read_to_string(&file).unwrap().contains("regex_dsl!"));
+
+    let source = "fn main() {\n    let regex = Regex::new(\"a\").unwrap();\n}\n";
+    fs::write(&file, source).unwrap();
+    let output = run(&["migrate", file.to_str().unwrap()], "");
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("main.rs:2:17: `Regex::new` was not migrated"));
+    assert_eq!(fs::read_to_string(&file).unwrap(), source);
+}
+
+#[test]
+fn invalid_arguments() {
+    assert!(!run(&["no-such-command"], "").status.success());
+    assert!(!run(&["convert", "-o", "xml", "a"], "").status.success());
+}
+
+#[test]
+fn explain() {
+    let output = run(&["explain", "-o", "plain", "\\d+$"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "one or more digits, then end of line\n");
+
+    let output = run(
+        &["explain", "--dsl"],
+        "concat {\n  \"a\",\n  maybe {\n    digit,\n  },\n}\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "For `concat {\n  \"a\",\n  maybe {\n    digit,\n  },\n}` the explanation would look like:\n```\nthe character 'a', then optionally a digit\n```\n\n"
+    );
+
+    let output = run(&["explain", "--dsl", "repeat { nothing }"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn near_miss() {
+    let output = run(&["near-miss", "-o", "plain", "-n", "2", "^ab$"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "\"\"\n\"b\"\n");
+
+    let output = run(
+        &["near-miss", "-o", "json", "--dsl"],
+        "concat { beginning_of_line, times { exactly: 2, digit }, end_of_line }",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "[{\"input\":\"concat { beginning_of_line, times { exactly: 2, digit }, end_of_line }\",\"output\":\"\\\"0\\\"\\n\\\"000\\\"\\n\\\"a0\\\"\"}]\n"
+    );
+
+    let output = run(&["near-miss", "[a"], "");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,72 @@
+use rust_regex_dsl_creator::ToExplanation;
+
+fn explain(regex: &str) -> String {
+    regex.to_explanation().unwrap()
+}
+
+#[test]
+fn simple_parts_are_in_a_single_line() {
+    assert_eq!(
+        explain("^[a-f_]+\\d{4}\\b"),
+        "beginning of line, then one or more characters from a to f or '_', then exactly 4 digits, then a word boundary\n"
+    );
+    assert_eq!(explain("x?"), "optionally the character 'x'\n");
+    assert_eq!(
+        explain("a|bc|"),
+        "either the character 'a' or the text \"bc\" or nothing\n"
+    );
+}
+
+#[test]
+fn classes() {
+    assert_eq!(
+        explain("[^[:alpha:]\\s]"),
+        "a character that is not from ASCII letters or white spaces\n"
+    );
+    assert_eq!(
+        explain("[\\w&&[^\\d]]"),
+        "a character from word characters (but only (anything but digits))\n"
+    );
+    assert_eq!(
+        explain("\\p{Greek}*"),
+        "zero or more characters from the unicode class Greek\n"
+    );
+    assert_eq!(
+        explain("\\P{Script=Greek}"),
+        "a character whose Script is not Greek\n"
+    );
+}
+
+#[test]
+fn repetitions() {
+    assert_eq!(
+        explain("a{2,}?"),
+        "at least 2 'a' characters (as few as possible)\n"
+    );
+    assert_eq!(explain("(?:ab){3}"), "the text \"ab\", exactly 3 times\n");
+    assert_eq!(
+        explain("(?:a\\d|b)+"),
+        "one or more times:\n  one of the following:\n    the character 'a', then a digit\n    or the character 'b'\n"
+    );
+}
+
+#[test]
+fn nested_parts_are_indented() {
+    assert_eq!(
+        explain("(?i)(?<word>foo(bar|baz)|qux)$"),
+        "from here on: case insensitive
+then captured as word:
+  one of the following:
+    the sequence:
+      the text \"foo\"
+      then either the text \"bar\" or the text \"baz\" (captured as group 2)
+    or the text \"qux\"
+then end of line
+"
+    );
+}
+
+#[test]
+fn invalid_regex() {
+    assert!("[a".to_explanation().is_err());
+}
@@ -0,0 +1,128 @@
+use rust_regex_dsl_creator::{migrate, AmbiguousCall};
+
+#[test]
+fn regex_new_is_migrated() {
+    let source = r#"
+use regex::Regex;
+
+fn main() {
+    let regex = Regex::new("a+").unwrap();
+    let other = regex::Regex::new(r"\d").expect("valid regex");
+}
+"#;
+    let expected = r#"
+use regex::Regex;
+
+fn main() {
+    let regex = rust_regex_dsl::regex_dsl! {
+        repeat {
+            eq("a"),
+        },
+    };
+    let other = rust_regex_dsl::regex_dsl! {
+        digit,
+    };
+}
+"#;
+    assert_eq!(migrate(source).unwrap().source, expected);
+}
+
+#[test]
+fn builder_is_migrated_with_options() {
+    let source = r#"use rust_regex_dsl::RegexBuilder; fn f() { RegexBuilder::new("a").case_insensitive(true).size_limit(1_000).line_terminator(b'\n').build().unwrap() }"#;
+    let expected = r#"use rust_regex_dsl::RegexBuilder; fn f() { rust_regex_dsl::regex_dsl! {
+    eq("a"),
+    case_insensitive = true,
+    size_limit = 1000,
+    line_terminator = 10,
+} }"#;
+    assert_eq!(migrate(source).unwrap().source, expected);
+}
+
+#[test]
+fn unsupported_regex_falls_back_to_regex() {
+    let source = r#"use regex::{Regex, RegexBuilder}; fn f() { Regex::new(r"()").unwrap(); RegexBuilder::new("a b").ignore_whitespace(true).build().unwrap(); }"#;
+    let expected = r#"use regex::{Regex, RegexBuilder}; fn f() { rust_regex_dsl::regex!(r"()"); rust_regex_dsl::regex!("a b", ignore_whitespace = true); }"#;
+    assert_eq!(migrate(source).unwrap().source, expected);
+}
+
+#[test]
+fn bytes_are_migrated() {
+    let source = r#"fn f() { regex::bytes::Regex::new("a").unwrap(); regex::bytes::Regex::new(r"(?-u)\xFF").unwrap(); }"#;
+    let expected = r#"fn f() { rust_regex_dsl::regex_dsl_bytes! {
+    eq("a"),
+}; rust_regex_dsl::regex_bytes!(r"(?-u)\xFF"); }"#;
+    assert_eq!(migrate(source).unwrap().source, expected);
+}
+
+#[test]
+fn imported_bytes_are_migrated() {
+    let source =
+        r#"use regex::bytes::Regex as BytesRegex; fn f() { BytesRegex::new("a").unwrap(); }"#;
+    let expected = r#"use regex::bytes::Regex as BytesRegex; fn f() { rust_regex_dsl::regex_dsl_bytes! {
+    eq("a"),
+}; }"#;
+    assert_eq!(migrate(source).unwrap().source, expected);
+}
+
+#[test]
+fn ambiguous_calls_are_reported() {
+    let source = r#"use regex::*;
+fn f() {
+    let regex = Regex::new("a").unwrap();
+    let builder = RegexBuilder::new("b").build().unwrap();
+    let invalid = Regex::new("(").unwrap();
+}
+"#;
+    let migration = migrate(source).unwrap();
+    assert_eq!(migration.source, source);
+    assert_eq!(
+        migration.ambiguous,
+        vec![
+            AmbiguousCall {
+                line: 3,
+                column: 17,
+                function: "Regex::new".to_string(),
+            },
+            AmbiguousCall {
+                line: 4,
+                column: 19,
+                function: "RegexBuilder::new".to_string(),
+            },
+        ]
+    );
+
+    // The same name is imported from two places.
+    let source = r#"
+mod a {
+    use regex::Regex;
+}
+mod b {
+    use regex::bytes::Regex;
+    fn f() { Regex::new("a").unwrap(); }
+}
+"#;
+    let migration = migrate(source).unwrap();
+    assert_eq!(migration.source, source);
+    assert_eq!(migration.ambiguous.len(), 1);
+}
+
+#[test]
+fn other_calls_are_not_changed() {
+    let source = r#"
+use fancy_regex::Regex;
+use regex::RegexBuilder;
+
+fn f(pattern: &str) {
+    let invalid = Regex::new("(").unwrap();
+    let not_literal = Regex::new(pattern).unwrap();
+    let not_unwrapped = Regex::new("a");
+    let unknown_option = RegexBuilder::new("a").no_such_option(true).build().unwrap();
+    let not_a_regex = Other::new("a").unwrap();
+    let fancy = fancy_regex::Regex::new("a+").unwrap();
+    let other_bytes = other::bytes::Regex::new("a+").unwrap();
+}
+"#;
+    assert_eq!(migrate(source).unwrap().source, source);
+    assert!(migrate("fn {").is_err());
+}
@@ -0,0 +1,35 @@
+use rust_regex_dsl_creator::{migrate, RustOutput, ToDsl};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io::Result;
+
+#[derive(Debug, Deserialize)]
+struct Test {
+    name: String,
+    regex: String,
+}
+
+/// The migrated code must be the same code that `test_builder` compiles and verifies (with the indentation of the
+/// function body).
+#[test]
+fn migrate_the_use_cases() -> Result<()> {
+    let test_cases_dir = env::current_dir()?.join("tests").join("test_cases");
+    for file in fs::read_dir(test_cases_dir)? {
+        let test: Test = toml::from_str(&fs::read_to_string(file?.path())?).unwrap();
+        println!("Migrating: {}", test.name);
+        let migrated = migrate(&format!(
+            "fn regex() -> rust_regex_dsl::Regex {{\n    regex::Regex::new({:?}).unwrap()\n}}",
+            test.regex
+        ))
+        .unwrap();
+        assert!(migrated.ambiguous.is_empty());
+        let code = test.regex.to_rust(&RustOutput::RegexDsl).unwrap();
+        let expected = format!(
+            "fn regex() -> rust_regex_dsl::Regex {{\n    {}\n}}",
+            code.trim_end().replace('\n', "\n    ")
+        );
+        assert_eq!(migrated.source, expected);
+    }
+    Ok(())
+}
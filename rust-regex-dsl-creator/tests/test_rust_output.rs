@@ -0,0 +1,57 @@
+use rust_regex_dsl_creator::{RustOutput, ToDsl};
+
+#[test]
+fn regex_dsl() {
+    let code = "a+".to_rust(&RustOutput::RegexDsl).unwrap();
+    assert_eq!(
+        code,
+        "rust_regex_dsl::regex_dsl! {\n    repeat {\n        eq(\"a\"),\n    },\n}\n"
+    );
+}
+
+#[test]
+fn create_capture_name_is_inferred() {
+    let code = "(?<first_name>\\w+) (?<last.name>\\w+) (\\d+)"
+        .to_rust(&RustOutput::CreateCapture(None))
+        .unwrap();
+    assert!(code.starts_with("rust_regex_dsl::create_capture!(\n    FirstName,\n"));
+    assert!(code.ends_with("    },\n);\n"));
+
+    let code = "(?<a>\\d+)-(?<a_b>\\d+)"
+        .to_rust(&RustOutput::CreateCapture(None))
+        .unwrap();
+    assert!(code.starts_with("rust_regex_dsl::create_capture!(\n    A,\n"));
+
+    let code = "\\d+".to_rust(&RustOutput::CreateCapture(None)).unwrap();
+    assert!(code.starts_with("rust_regex_dsl::create_capture!(\n    Capture,\n"));
+
+    let code = "(?<a>\\d+)"
+        .to_rust(&RustOutput::CreateCapture(Some("Number".into())))
+        .unwrap();
+    assert!(code.starts_with("rust_regex_dsl::create_capture!(\n    Number,\n"));
+}
+
+#[test]
+fn lazy_lock_name_is_inferred() {
+    let code = "(?<year>\\d{4})-(?<month>\\d{2})"
+        .to_rust(&RustOutput::LazyLock(None))
+        .unwrap();
+    assert!(code.starts_with("static YEAR_REGEX: std::sync::LazyLock<rust_regex_dsl::Regex>"));
+
+    let code = "\\d"
+        .to_rust(&RustOutput::LazyLock(Some("DIGIT".into())))
+        .unwrap();
+    assert!(code.starts_with("static DIGIT: "));
+}
+
+#[test]
+fn invalid_regex() {
+    assert!("(".to_rust(&RustOutput::RegexDsl).is_err());
+}
+
+#[test]
+fn empty_regex() {
+    assert!("".to_rust(&RustOutput::RegexDsl).is_err());
+    assert!("".to_rust(&RustOutput::CreateCapture(None)).is_err());
+    assert!("".to_rust(&RustOutput::LazyLock(None)).is_err());
+}
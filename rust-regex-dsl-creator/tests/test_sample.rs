@@ -0,0 +1,148 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use regex::Regex;
+use rust_regex_dsl_creator::Sampler;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(42)
+}
+
+#[test]
+fn random_samples_match() {
+    for regex in [
+        "[a-z]+@[a-z]+\\.(com|org)",
+        "\\d{3}-\\d{4}",
+        "(?i)hello \\w*",
+        "\\bfoo\\b",
+        "^(a|b|)c?$",
+        "\\p{Greek}{2,}",
+        ".{5}",
+    ] {
+        let sampler = Sampler::new(regex).unwrap();
+        let full = Regex::new(&format!("^(?:{})$", regex)).unwrap();
+        let mut rng = rng();
+        for _ in 0..20 {
+            let sample = sampler.sample(&mut rng).unwrap();
+            assert!(
+                full.is_match(&sample),
+                "{} should match {:?}",
+                regex,
+                sample
+            );
+        }
+    }
+}
+
+#[test]
+fn repetitions_are_bounded() {
+    let sampler = Sampler::new("a*").unwrap().with_max_repeat(2);
+    let mut rng = rng();
+    for _ in 0..20 {
+        assert!(sampler.sample(&mut rng).unwrap().len() <= 2);
+    }
+    assert_eq!(sampler.enumerate(10), vec!["", "a", "aa"]);
+    assert_eq!(
+        Sampler::new("a{1,100}").unwrap().enumerate(100),
+        vec!["a", "aa", "aaa", "aaaa", "aaaaa"]
+    );
+}
+
+#[test]
+fn enumerate_in_order() {
+    let sampler = Sampler::new("[ab][0-2]|x").unwrap();
+    assert_eq!(
+        sampler.enumerate(10),
+        vec!["a0", "x", "a1", "a2", "b0", "b1", "b2"]
+    );
+    assert_eq!(sampler.enumerate(2), vec!["a0", "x"]);
+}
+
+#[test]
+fn enumerate_respects_assertions() {
+    let sampler = Sampler::new("a\\b[ b]").unwrap();
+    assert_eq!(sampler.enumerate(10), vec!["a "]);
+}
+
+#[test]
+fn bytes() {
+    let sampler = Sampler::new_bytes("(?-u:[\\x80-\\x81])").unwrap();
+    assert_eq!(sampler.enumerate_bytes(10), vec![vec![0x80], vec![0x81]]);
+    assert!(sampler.sample_bytes(&mut rng()).is_some());
+    assert!(sampler.sample(&mut rng()).is_none());
+}
+
+#[test]
+fn impossible() {
+    let sampler = Sampler::new("a\\bb").unwrap();
+    assert!(sampler.sample(&mut rng()).is_none());
+    assert!(sampler.enumerate(10).is_empty());
+}
+
+#[test]
+fn invalid_regex() {
+    assert!(Sampler::new("[a").is_err());
+    assert!(Sampler::new("(?-u:\\xFF)").is_err());
+    assert!(Sampler::new_bytes("(?-u:\\xFF)").is_ok());
+}
+
+#[test]
+fn near_misses_are_rejected() {
+    for regex in [
+        "^[a-z]+@[a-z]+\\.(com|org)$",
+        "^\\d{3}-\\d{4}$",
+        "^(?i)hello \\w*$",
+        "\\bfoo\\b",
+        "^x{2,5}$",
+        "[^a-c]",
+    ] {
+        let sampler = Sampler::new(regex).unwrap();
+        let near_misses = sampler.near_misses(20);
+        assert!(!near_misses.is_empty(), "No near misses for {}", regex);
+        let regex = Regex::new(regex).unwrap();
+        for near_miss in near_misses {
+            assert!(
+                !regex.is_match(&near_miss),
+                "{} matches {:?}",
+                regex,
+                near_miss
+            );
+        }
+    }
+}
+
+#[test]
+fn near_misses_mutations() {
+    // Too few, too many, dropped or replaced character.
+    assert_eq!(
+        Sampler::new("^x{2,3}$").unwrap().near_misses(10),
+        vec!["x", "xxxx", "ax"]
+    );
+    // A character outside of the class.
+    assert_eq!(
+        Sampler::new("^[a-z]$").unwrap().near_misses(10),
+        vec!["", "0"]
+    );
+    // A dropped literal.
+    assert_eq!(
+        Sampler::new("^ab$").unwrap().near_misses(10),
+        vec!["", "b", "0b"]
+    );
+    assert_eq!(Sampler::new("^ab$").unwrap().near_misses(1), vec![""]);
+    assert!(Sampler::new("^ab$").unwrap().near_misses(0).is_empty());
+    // Without anchors, a longer string still contains a match.
+    assert_eq!(
+        Sampler::new("x{2,3}").unwrap().near_misses(10),
+        vec!["x", "ax"]
+    );
+    // Nothing is rejected.
+    assert!(Sampler::new("a*").unwrap().near_misses(10).is_empty());
+}
+
+#[test]
+fn near_misses_bytes() {
+    let sampler = Sampler::new_bytes("^(?-u:\\xFF)a$").unwrap();
+    assert_eq!(
+        sampler.near_misses_bytes(10),
+        vec![vec![], vec![b'a'], vec![b'a', b'a']]
+    );
+}
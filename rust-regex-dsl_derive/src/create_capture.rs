@@ -1,137 +1,552 @@
 use convert_case::{Case, Casing};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
-use regex::Regex;
 use syn::parse::Parse;
-use syn::token::Comma;
 
-use crate::dsl::Dsl;
 use crate::error_factory::ErrorFactory;
-use crate::functions::parse_list::parse_list_to_vec;
+use crate::participation::Participation;
+use regex_syntax::hir::{Capture, Hir, HirKind, Repetition};
+use regex_syntax::ParserBuilder;
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::functions::parse_ident::parse_ident;
+use rust_regex_dsl_core::functions::parse_list::{parse_list_to_vec_with_options, skip_comma};
+use rust_regex_dsl_core::options::RegexOptions;
+use rust_regex_dsl_core::{Dsl, GroupType};
 use syn::parse::ParseStream;
-use syn::{Ident, Index, LitStr, Result};
+use syn::{Error, Ident, Index, LitStr, Result, Token};
 
 pub struct CreateCapture {
     struct_name: String,
+    owned: bool,
+    enum_capture: bool,
+    flavor: Flavor,
     regex: String,
+    group_types: Vec<GroupType>,
+    options: RegexOptions,
 }
 impl Parse for CreateCapture {
     fn parse(input: ParseStream) -> Result<Self> {
         let struct_name: Ident = input.parse()?;
         let struct_name = struct_name.to_string();
-        let lookahead = input.lookahead1();
-        if lookahead.peek(Comma) {
-            let _: Comma = input.parse()?;
+        skip_comma(input)?;
+        let mut owned = false;
+        let mut enum_capture = false;
+        let mut flavor = Flavor::Str;
+        loop {
+            let span = input.span();
+            if parse_ident(input, "owned")? {
+                owned = true;
+            } else if input.peek(Token![enum]) {
+                let _: Token![enum] = input.parse()?;
+                enum_capture = true;
+            } else if parse_ident(input, "bytes")? {
+                if flavor != Flavor::Str {
+                    return Err(Error::new(span, "Only one of bytes and fancy can be used"));
+                }
+                flavor = Flavor::Bytes;
+            } else if parse_ident(input, "fancy")? {
+                if flavor != Flavor::Str {
+                    return Err(Error::new(span, "Only one of bytes and fancy can be used"));
+                }
+                flavor = Flavor::fancy(span)?;
+            } else {
+                break;
+            }
+            skip_comma(input)?;
         }
         let lookahead = input.lookahead1();
-        let regex = if lookahead.peek(LitStr) {
+        let (regex, group_types, options) = if lookahead.peek(LitStr) {
             let regex: LitStr = input.parse()?;
-            regex.value()
+            skip_comma(input)?;
+            (regex.value(), vec![], input.parse()?)
         } else {
-            let items: Vec<Dsl> = parse_list_to_vec(input)?;
+            let (items, options): (Vec<Dsl>, _) = parse_list_to_vec_with_options(input)?;
             if items.is_empty() {
                 return Err(input.error("Nothing to capture"));
             }
 
             let dsl = Dsl::concat(&items);
-            dsl.regex().to_string()
+            (
+                dsl.as_str().to_string(),
+                dsl.group_types().to_vec(),
+                options,
+            )
         };
-        Ok(CreateCapture { struct_name, regex })
+        Ok(CreateCapture {
+            struct_name,
+            owned,
+            enum_capture,
+            flavor,
+            regex,
+            group_types,
+            options,
+        })
+    }
+}
+struct CaptureGroup {
+    index: Index,
+    name: Option<Ident>,
+    always: bool,
+    group_type: Option<TokenStream>,
+}
+impl CaptureGroup {
+    fn getter(&self, value: TokenStream, str_type: &TokenStream) -> Option<TokenStream> {
+        let name = self.name.as_ref()?;
+        let getter = match (&self.group_type, self.always) {
+            (Some(group_type), true) => quote! {
+                pub fn #name(&self) -> Result<#group_type, <#group_type as std::str::FromStr>::Err> {
+                     #value.parse()
+                }
+            },
+            (Some(group_type), false) => quote! {
+                pub fn #name(&self) -> Result<Option<#group_type>, <#group_type as std::str::FromStr>::Err> {
+                     #value.map(str::parse).transpose()
+                }
+            },
+            (None, true) => quote! {
+                pub fn #name(&self) -> #str_type {
+                     #value
+                }
+            },
+            (None, false) => quote! {
+                pub fn #name(&self) -> Option<#str_type> {
+                     #value
+                }
+            },
+        };
+        Some(getter)
+    }
+    fn range_getter(&self, ranges: &Index) -> Option<TokenStream> {
+        let name = format_ident!("{}_range", self.name.as_ref()?);
+        let i = self.index.index as usize;
+        let getter = if self.always {
+            quote! {
+                pub fn #name(&self) -> std::ops::Range<usize> {
+                     self.#ranges[#i].clone().unwrap()
+                }
+            }
+        } else {
+            quote! {
+                pub fn #name(&self) -> Option<std::ops::Range<usize>> {
+                     self.#ranges[#i].clone()
+                }
+            }
+        };
+        Some(getter)
     }
 }
 impl CreateCapture {
     pub fn build(&self, error_factory: ErrorFactory) -> TokenStream {
         let regex_str = self.regex.as_str();
-        let regex = match Regex::new(regex_str) {
-            Ok(regex) => regex,
+        if let Err(err) = self.options.check(self.flavor) {
+            return err.to_compile_error();
+        }
+        let names = match self.options.capture_names(self.flavor, regex_str) {
+            Ok(names) => names,
             Err(e) => {
+                let e = self.flavor.with_fancy_hint(e, regex_str);
                 return error_factory.error(format!("Invalid regular expression: {}", e));
             }
         };
-        if regex
-            .capture_names()
+        if let Err(err) = self.options.check_examples(self.flavor, regex_str) {
+            return err.to_compile_error();
+        }
+        if let Some(name) = names
+            .iter()
             .flatten()
-            .any(|nm| nm == "get_capture")
+            .find(|nm| *nm == "get_capture" || *nm == "get_capture_range")
         {
-            return error_factory.error("The name get_capture in not supported".to_string());
+            return error_factory.error(format!("The name {} in not supported", name));
         }
+        if self.flavor == Flavor::Bytes && !self.group_types.is_empty() {
+            return error_factory.error("Typed groups are not supported with bytes".to_string());
+        }
+        if self.enum_capture {
+            return self.build_enum(&names, error_factory);
+        }
+        let regex_type = self.flavor.regex_type();
+        let captures_type = self.flavor.captures_type();
+        let haystack_type = self.flavor.haystack_type();
+        let as_haystack = self.flavor.as_haystack();
         let struct_name = format_ident!("{}", self.struct_name);
-        let names = regex.capture_names();
-        let len = names.len();
         let regex_name = format_ident!("{}_REGEX", self.struct_name.to_case(Case::UpperSnake));
+        let new_regex = self.options.new_regex(self.flavor, regex_str);
+        let captures = self
+            .flavor
+            .captures(quote! { #regex_name }, quote! { Self::new });
+        let captures_iter = self
+            .flavor
+            .captures_iter(quote! { #regex_name }, quote! { Self::new });
+        let catch_type = self.flavor.catch_type(quote! { #struct_name<'h> });
+        let catch_all_type = self.flavor.catch_all_type(quote! { #struct_name<'h> });
         let define_regex = quote! {
-            static #regex_name: std::sync::LazyLock<rust_regex_dsl::Regex> = std::sync::LazyLock::new(|| rust_regex_dsl::Regex::new(#regex_str).unwrap());
+            static #regex_name: std::sync::LazyLock<#regex_type> = std::sync::LazyLock::new(|| #new_regex);
         };
 
-        let args: Vec<_> = (1..len).map(|_| quote! {, Option<&'h str>}).collect();
+        let participation = Participation::new(regex_str, self.flavor, &self.options);
+        let groups: Vec<_> = names
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, name)| CaptureGroup {
+                index: Index::from(i),
+                name: name
+                    .as_ref()
+                    .map(|name| format_ident!("{}", name.to_case(Case::Snake))),
+                always: participation.always_participate(i),
+                group_type: self
+                    .group_types
+                    .iter()
+                    .find(|t| Some(&t.name) == name.as_ref())
+                    .map(|t| t.group_type.clone()),
+            })
+            .collect();
+        let args: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                if group.always {
+                    quote! {, &'h #haystack_type}
+                } else {
+                    quote! {, Option<&'h #haystack_type>}
+                }
+            })
+            .collect();
+        let len = groups.len() + 1;
+        let ranges = Index::from(len);
         let define_struct = quote! {
             #[derive(Debug)]
-            struct #struct_name<'h>(&'h str #(#args)*);
+            struct #struct_name<'h>(&'h #haystack_type #(#args)*, [Option<std::ops::Range<usize>>; #len]);
         };
 
         let get_capture = quote! {
-            pub fn get_capture(&self) -> &'h str {
+            pub fn get_capture(&self) -> &'h #haystack_type {
                  self.0
             }
+            pub fn get_capture_range(&self) -> std::ops::Range<usize> {
+                 self.#ranges[0].clone().unwrap()
+            }
         };
 
-        let get_names: Vec<_> = names
-            .into_iter()
-            .enumerate()
-            .filter_map(|(i, cap)| cap.map(|c| (i, c)))
-            .map(|(i, name)| {
-                let name = format_ident!("{}", name.to_case(Case::Snake));
-                let i = Index::from(i);
-                quote! {
-                    pub fn #name(&self) -> Option<&'h str> {
-                         self.#i
-                    }
-                }
+        let str_type = quote! { &'h #haystack_type };
+        let get_names: Vec<_> = groups
+            .iter()
+            .filter_map(|group| {
+                let i = &group.index;
+                group.getter(quote! { self.#i }, &str_type)
             })
             .collect();
-        let capture_args: Vec<_> = (1..len)
-            .map(|i| {
-                let i = Index::from(i);
-                quote! {
-                    , captures.get(#i).map(|c| c.as_str())
+        let get_ranges: Vec<_> = groups
+            .iter()
+            .filter_map(|group| group.range_getter(&ranges))
+            .collect();
+        let capture_args: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                let i = &group.index;
+                if group.always {
+                    quote! {
+                        , captures.get(#i).unwrap().#as_haystack()
+                    }
+                } else {
+                    quote! {
+                        , captures.get(#i).map(|c| c.#as_haystack())
+                    }
                 }
             })
             .collect();
 
+        let range_indices = 0..len;
         let new = quote! {
-            fn new(captures: rust_regex_dsl::Captures<'h>) -> Self {
-                Self(captures.get(0).unwrap().as_str() #(#capture_args)*)
+            fn new(captures: #captures_type<'h>) -> Self {
+                Self(captures.get(0).unwrap().#as_haystack() #(#capture_args)*, [#(captures.get(#range_indices).map(|c| c.range())),*])
             }
         };
         let capture = quote! {
-            pub fn catch(haystack: &'h str) -> Option<#struct_name<'h>> {
-                #regex_name.captures(haystack).map(Self::new)
+            pub fn catch(haystack: &'h #haystack_type) -> #catch_type {
+                #captures
             }
         };
         let capture_all = quote! {
-            pub fn catch_all(haystack: &'h str) -> impl Iterator<Item = #struct_name<'h>> {
-                #regex_name.captures_iter(haystack).map(Self::new)
+            pub fn catch_all(haystack: &'h #haystack_type) -> impl Iterator<Item = #catch_all_type> {
+                #captures_iter
             }
         };
         let get_regex = quote! {
-            pub fn regex() -> &'static rust_regex_dsl::Regex {
+            pub fn regex() -> &'static #regex_type {
                 &*#regex_name
             }
         };
+        let sample = self.build_sample();
+        let strategy = self.build_strategy(&regex_name);
+        let owned = if self.owned {
+            self.build_owned(&struct_name, &groups, &ranges)
+        } else {
+            quote! {}
+        };
         let impl_getters = quote! {
             impl <'h> #struct_name<'h> {
                 #new
                 #get_capture
                 #(#get_names)*
+                #(#get_ranges)*
                 #capture
                 #capture_all
                 #get_regex
+                #sample
+                #strategy
             }
         };
         quote! {
             #define_regex
             #define_struct
             #impl_getters
+            #owned
+        }
+    }
+
+    fn build_enum(&self, names: &[Option<String>], error_factory: ErrorFactory) -> TokenStream {
+        if self.owned {
+            return error_factory.error("An enum capture can not be owned".to_string());
+        }
+        if !self.group_types.is_empty() {
+            return error_factory.error("Typed groups are not supported with enum".to_string());
+        }
+        let regex_str = self.regex.as_str();
+        let Some(alternatives) = top_level_alternatives(regex_str, self.flavor) else {
+            return error_factory
+                .error("An enum capture must be an any of named groups".to_string());
+        };
+        let mut indices = vec![];
+        let mut variants = vec![];
+        for index in alternatives {
+            let Some(Some(name)) = names.get(index) else {
+                return error_factory
+                    .error("An enum capture must be an any of named groups".to_string());
+            };
+            indices.push(index);
+            variants.push(format_ident!("{}", name.to_case(Case::UpperCamel)));
+        }
+        let regex_type = self.flavor.regex_type();
+        let captures_type = self.flavor.captures_type();
+        let haystack_type = self.flavor.haystack_type();
+        let as_haystack = self.flavor.as_haystack();
+        let enum_name = format_ident!("{}", self.struct_name);
+        let regex_name = format_ident!("{}_REGEX", self.struct_name.to_case(Case::UpperSnake));
+        let new_regex = self.options.new_regex(self.flavor, regex_str);
+        let captures = self
+            .flavor
+            .captures(quote! { #regex_name }, quote! { Self::new });
+        let captures_iter = self
+            .flavor
+            .captures_iter(quote! { #regex_name }, quote! { Self::new });
+        let catch_type = self.flavor.catch_type(quote! { #enum_name<'h> });
+        let catch_all_type = self.flavor.catch_all_type(quote! { #enum_name<'h> });
+        let sample = self.build_sample();
+        let strategy = self.build_strategy(&regex_name);
+        quote! {
+            static #regex_name: std::sync::LazyLock<#regex_type> = std::sync::LazyLock::new(|| #new_regex);
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            enum #enum_name<'h> {
+                #(#variants(&'h #haystack_type)),*
+            }
+            impl <'h> #enum_name<'h> {
+                fn new(captures: #captures_type<'h>) -> Self {
+                    #(
+                        if let Some(capture) = captures.get(#indices) {
+                            return Self::#variants(capture.#as_haystack());
+                        }
+                    )*
+                    unreachable!()
+                }
+                pub fn get_capture(&self) -> &'h #haystack_type {
+                    match self {
+                        #(Self::#variants(capture))|* => capture,
+                    }
+                }
+                pub fn catch(haystack: &'h #haystack_type) -> #catch_type {
+                    #captures
+                }
+                pub fn catch_all(haystack: &'h #haystack_type) -> impl Iterator<Item = #catch_all_type> {
+                    #captures_iter
+                }
+                pub fn regex() -> &'static #regex_type {
+                    &*#regex_name
+                }
+                #sample
+                #strategy
+            }
+        }
+    }
+
+    /// The regular expression with the options as inline flags, and its HIR (if the options can be expressed as flags,
+    /// and the regular expression does not use look-around or backreferences).
+    fn inline_regex(&self) -> Option<(String, Hir)> {
+        let regex = format!("{}{}", self.options.inline_flags()?, self.regex);
+        let mut parser = ParserBuilder::new().utf8(self.flavor.utf8()).build();
+        let hir = parser.parse(&regex).ok()?;
+        Some((regex, hir))
+    }
+
+    /// The `sample` function (with the `sample` feature), if the regular expression can be sampled.
+    fn build_sample(&self) -> TokenStream {
+        if !cfg!(feature = "sample") {
+            return quote! {};
+        }
+        let Some((regex, _)) = self.inline_regex() else {
+            return quote! {};
+        };
+        let regex = Literal::string(&regex);
+        let (new_sampler, sample, sample_type) = if self.flavor == Flavor::Bytes {
+            (
+                quote! { new_bytes },
+                quote! { sample_bytes },
+                quote! { Vec<u8> },
+            )
+        } else {
+            (quote! { new }, quote! { sample }, quote! { String })
+        };
+        quote! {
+            pub fn sample<R: rust_regex_dsl::rand::Rng + ?Sized>(rng: &mut R) -> Option<#sample_type> {
+                static SAMPLER: std::sync::LazyLock<rust_regex_dsl::Sampler> = std::sync::LazyLock::new(|| rust_regex_dsl::Sampler::#new_sampler(#regex).unwrap());
+                SAMPLER.#sample(rng)
+            }
+        }
+    }
+
+    /// The `strategy` function (with the `proptest` feature), if the regular expression can be generated.
+    fn build_strategy(&self, regex_name: &Ident) -> TokenStream {
+        if !cfg!(feature = "proptest") {
+            return quote! {};
+        }
+        let Some((_, hir)) = self.inline_regex() else {
+            return quote! {};
+        };
+        // proptest can not generate assertions, so they are removed, and the results are filtered by the regex.
+        let regex = Literal::string(&without_assertions(&hir).to_string());
+        let (new_strategy, value_type) = if self.flavor == Flavor::Bytes {
+            (quote! { bytes_regex }, quote! { Vec<u8> })
+        } else {
+            (quote! { string_regex }, quote! { String })
+        };
+        let is_match = self.flavor.is_match(quote! { #regex_name });
+        quote! {
+            pub fn strategy() -> impl rust_regex_dsl::proptest::strategy::Strategy<Value = #value_type> {
+                use rust_regex_dsl::proptest::strategy::Strategy;
+                rust_regex_dsl::proptest::string::#new_strategy(#regex)
+                    .unwrap()
+                    .prop_filter("Must match the regular expression", |haystack| #is_match)
+            }
+        }
+    }
+
+    fn build_owned(
+        &self,
+        struct_name: &Ident,
+        groups: &[CaptureGroup],
+        ranges: &Index,
+    ) -> TokenStream {
+        let owned_name = format_ident!("{}Owned", self.struct_name);
+        let haystack_type = self.flavor.haystack_type();
+        let owned_type = self.flavor.owned_type();
+        let as_haystack = self.flavor.owned_as_haystack();
+        let args: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                if group.always {
+                    quote! {, #owned_type}
+                } else {
+                    quote! {, Option<#owned_type>}
+                }
+            })
+            .collect();
+        let define_struct = quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            struct #owned_name(#owned_type #(#args)*, [Option<std::ops::Range<usize>>; #ranges]);
+        };
+        let str_type = quote! { &#haystack_type };
+        let get_names: Vec<_> = groups
+            .iter()
+            .filter_map(|group| {
+                let i = &group.index;
+                if group.always {
+                    group.getter(quote! { self.#i.#as_haystack() }, &str_type)
+                } else {
+                    group.getter(quote! { self.#i.as_deref() }, &str_type)
+                }
+            })
+            .collect();
+        let get_ranges: Vec<_> = groups
+            .iter()
+            .filter_map(|group| group.range_getter(ranges))
+            .collect();
+        let from_args: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                let i = &group.index;
+                if group.always {
+                    quote! {, capture.#i.to_owned()}
+                } else {
+                    quote! {, capture.#i.map(<#haystack_type>::to_owned)}
+                }
+            })
+            .collect();
+        quote! {
+            #define_struct
+            impl #owned_name {
+                pub fn get_capture(&self) -> &#haystack_type {
+                     self.0.#as_haystack()
+                }
+                pub fn get_capture_range(&self) -> std::ops::Range<usize> {
+                     self.#ranges[0].clone().unwrap()
+                }
+                #(#get_names)*
+                #(#get_ranges)*
+            }
+            impl <'h> From<#struct_name<'h>> for #owned_name {
+                fn from(capture: #struct_name<'h>) -> Self {
+                    Self(capture.0.to_owned() #(#from_args)*, capture.#ranges)
+                }
+            }
+            impl <'h> #struct_name<'h> {
+                pub fn into_owned(self) -> #owned_name {
+                    self.into()
+                }
+            }
         }
     }
 }
+
+/// The same HIR, without the assertions (i.e. `^` or `\b`).
+fn without_assertions(hir: &Hir) -> Hir {
+    match hir.kind() {
+        HirKind::Look(_) => Hir::empty(),
+        HirKind::Repetition(repetition) => Hir::repetition(Repetition {
+            sub: Box::new(without_assertions(&repetition.sub)),
+            ..repetition.clone()
+        }),
+        HirKind::Capture(capture) => Hir::capture(Capture {
+            sub: Box::new(without_assertions(&capture.sub)),
+            ..capture.clone()
+        }),
+        HirKind::Concat(hirs) => Hir::concat(hirs.iter().map(without_assertions).collect()),
+        HirKind::Alternation(hirs) => {
+            Hir::alternation(hirs.iter().map(without_assertions).collect())
+        }
+        _ => hir.clone(),
+    }
+}
+
+fn top_level_alternatives(regex: &str, flavor: Flavor) -> Option<Vec<usize>> {
+    let mut parser = ParserBuilder::new().utf8(flavor.utf8()).build();
+    let hir = parser.parse(regex).ok()?;
+    let HirKind::Alternation(alternatives) = hir.kind() else {
+        return None;
+    };
+    alternatives
+        .iter()
+        .map(|alternative| match alternative.kind() {
+            HirKind::Capture(capture) => Some(capture.index as usize),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Ident, Result};
+
+use crate::error_factory::ErrorFactory;
+use rust_regex_dsl_core::functions::parse_list::{parse_list_to_vec, skip_comma};
+use rust_regex_dsl_core::Dsl;
+
+pub struct DefineDsl {
+    name: Ident,
+    tokens: TokenStream,
+    dsl: Dsl,
+}
+impl Parse for DefineDsl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        skip_comma(input)?;
+        let tokens: TokenStream = input.fork().parse()?;
+        let items: Vec<Dsl> = parse_list_to_vec(input)?;
+        if items.is_empty() {
+            return Err(Error::new(name.span(), "Nothing to define"));
+        }
+        Ok(DefineDsl {
+            name,
+            tokens,
+            dsl: Dsl::concat(&items),
+        })
+    }
+}
+impl DefineDsl {
+    pub fn build(&self, preamble: TokenStream) -> TokenStream {
+        // The final regular expression is validated with the actual flavor.
+        if let Some(err) = self.dsl.validate_any() {
+            return ErrorFactory::new_obj(self.name.span()).error(err);
+        }
+        let name = &self.name;
+        let tokens = &self.tokens;
+        quote! {
+            macro_rules! #name {
+                (@callback $($callback:ident)::+ { $($input:tt)* }) => {
+                    $($callback)::+! { #preamble @fragment #name { #tokens } $($input)* }
+                };
+            }
+        }
+    }
+}
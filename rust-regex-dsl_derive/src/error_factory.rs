@@ -14,7 +14,7 @@ impl ErrorFactory {
     }
     pub fn error(&self, message: String) -> TokenStream {
         match self {
-            ErrorFactory::RootErrorFactory => quote! { compile_error!(#message) },
+            ErrorFactory::RootErrorFactory => quote! { compile_error! { #message } },
             ErrorFactory::ObjectErrorFactory(span) => Error::new(*span, message).to_compile_error(),
         }
     }
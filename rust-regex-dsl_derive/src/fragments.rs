@@ -0,0 +1,94 @@
+use proc_macro2::{Delimiter, Ident, TokenStream, TokenTree};
+use quote::quote;
+use rust_regex_dsl_core::fragments::with_fragments;
+
+/// The DSL fragments that were spliced before the input of a macro (as `@fragment <name> { <dsl> }`).
+pub struct Fragments {
+    fragments: Vec<(Ident, TokenStream)>,
+    input: TokenStream,
+}
+
+impl Fragments {
+    pub fn new(input: TokenStream) -> Self {
+        let mut fragments = vec![];
+        let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+        while let [TokenTree::Punct(at), TokenTree::Ident(keyword), TokenTree::Ident(name), TokenTree::Group(group), ..] =
+            tokens.as_slice()
+        {
+            if at.as_char() != '@' || keyword != "fragment" || group.delimiter() != Delimiter::Brace
+            {
+                break;
+            }
+            fragments.push((name.clone(), group.stream()));
+            tokens.drain(..4);
+        }
+        Fragments {
+            fragments,
+            input: tokens.into_iter().collect(),
+        }
+    }
+
+    /// If the input uses a fragment that was not spliced yet, return an invocation of the fragment macro that will
+    /// call the macro again (using `callback`) with the fragment spliced.
+    pub fn splice_missing(&self, callback: TokenStream) -> Option<TokenStream> {
+        let missing = self
+            .fragments
+            .iter()
+            .map(|(_, fragment)| fragment)
+            .chain([&self.input])
+            .find_map(|tokens| self.find_missing(tokens))?;
+        let preamble = self.preamble();
+        let input = &self.input;
+        Some(quote! {
+            #missing! {
+                @callback #callback { #preamble #input }
+            }
+        })
+    }
+
+    pub fn preamble(&self) -> TokenStream {
+        let fragments = self.fragments.iter().map(|(name, fragment)| {
+            quote! { @fragment #name { #fragment } }
+        });
+        quote! { #(#fragments)* }
+    }
+
+    pub fn input(&self) -> TokenStream {
+        self.input.clone()
+    }
+
+    /// Make the fragments available to `use_fragment` while `f` is running.
+    pub fn with<T>(&self, f: impl FnOnce() -> T) -> T {
+        let fragments = self
+            .fragments
+            .iter()
+            .map(|(name, fragment)| (name.to_string(), fragment.clone()))
+            .collect();
+        with_fragments(fragments, f)
+    }
+
+    fn find_missing(&self, tokens: &TokenStream) -> Option<Ident> {
+        let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                TokenTree::Ident(ident) if ident == "use_fragment" => {
+                    if let Some(TokenTree::Group(group)) = tokens.get(i + 1) {
+                        let mut args = group.stream().into_iter();
+                        if let (Some(TokenTree::Ident(name)), None) = (args.next(), args.next()) {
+                            if !self.fragments.iter().any(|(known, _)| *known == name) {
+                                return Some(name);
+                            }
+                        }
+                    }
+                }
+                TokenTree::Group(group) => {
+                    if let Some(missing) = self.find_missing(&group.stream()) {
+                        return Some(missing);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
@@ -1,19 +1,90 @@
 //! Use the [rust-regex-dsl](https://github.com/yift/rust-regex-dsl/) package.
 
 use create_capture::CreateCapture;
-use dsl::Dsl;
+use define_dsl::DefineDsl;
 use error_factory::ErrorFactory;
-use functions::parse_list::parse_list_to_vec;
+use fragments::Fragments;
+use literal_error::literal_error;
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, LitStr};
+use quote::quote;
+use regex_capture::RegexCapture;
+use regex_set::RegexSet;
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::functions::parse_list::{
+    parse_list_to_vec_with_options, parse_static, skip_comma,
+};
+use rust_regex_dsl_core::options::RegexOptions;
+use rust_regex_dsl_core::Dsl;
+use syn::parse::ParseStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
 mod create_capture;
-mod dsl;
+mod define_dsl;
 mod error_factory;
-mod functions;
-mod group;
-mod ident_parser;
-mod predefined_class;
-mod user_class;
+mod fragments;
+mod literal;
+mod literal_error;
+mod participation;
+mod regex_capture;
+mod regex_set;
+
+fn parse_static_literal(input: ParseStream) -> syn::Result<(bool, LitStr, RegexOptions)> {
+    let is_static = parse_static(input)?;
+    let literal = input.parse()?;
+    skip_comma(input)?;
+    Ok((is_static, literal, input.parse()?))
+}
+
+fn parse_static_dsls(input: ParseStream) -> syn::Result<(bool, Vec<Dsl>, RegexOptions)> {
+    let is_static = parse_static(input)?;
+    let (dsls, options) = parse_list_to_vec_with_options(input)?;
+    Ok((is_static, dsls, options))
+}
+
+fn with_fragments(
+    input: TokenStream,
+    callback: proc_macro2::TokenStream,
+    f: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    let fragments = Fragments::new(input.into());
+    if let Some(splice) = fragments.splice_missing(callback) {
+        return splice.into();
+    }
+    fragments.with(|| f(fragments.input().into()))
+}
+
+fn build(
+    dsl: &Dsl,
+    is_static: bool,
+    flavor: Flavor,
+    options: &RegexOptions,
+    error_factory: ErrorFactory,
+) -> TokenStream {
+    if let Err(err) = options.check(flavor) {
+        return err.to_compile_error().into();
+    }
+    if let Some(err) = dsl.validate_with(flavor, options) {
+        return error_factory.error(err).into();
+    }
+    if let Err(err) = options.check_examples(flavor, dsl.as_str()) {
+        // There might be a few errors, and the result is an expression.
+        let errors = err.to_compile_error();
+        return quote! { { #errors } }.into();
+    }
+
+    let regex_type = flavor.regex_type();
+    let new_regex = options.new_regex(flavor, dsl.as_str());
+    if is_static {
+        quote! {
+            {
+                static REGEX: std::sync::LazyLock<#regex_type> = std::sync::LazyLock::new(|| #new_regex);
+                &*REGEX
+            }
+        }
+        .into()
+    } else {
+        new_regex.into()
+    }
+}
 
 /// A simple regular expression macro.
 ///
@@ -46,13 +117,101 @@ mod user_class;
 /// static VALID_NAME: LazyLock<Regex> = LazyLock::new(|| regex!("[a-z][a-zA-Z_*"));
 /// ```
 /// will fail with compilation error (because of the missing closing square bracket) while using the same without the macro will fail in run time.
+/// With a nightly compiler, the error points to the part of the literal that caused it.
+///
+/// If the regular expression starts with `static` (i.e. `regex!(static "...")`), the result will be a `&'static` [`regex::Regex`] that
+/// is compiled only once (on first use), so it can be used in a loop without compiling the expression on every iteration:
+/// ```rust
+/// use rust_regex_dsl::regex;
+///
+/// for word in ["foo", "Bar", "baz"] {
+///     let valid_name = regex!(static "^[a-z][a-zA-Z_]*$");
+///     assert_eq!(valid_name.is_match(word), word != "Bar");
+/// }
+/// ```
+///
+/// The regular expression can be followed by [`regex::RegexBuilder`] options (as `name = value`). The expression is validated with
+/// the same options, and the result is built with a [`regex::RegexBuilder`]. The supported options are `unicode`, `case_insensitive`,
+/// `multi_line`, `dot_matches_new_line`, `crlf`, `swap_greed`, `ignore_whitespace`, `octal`, `size_limit`, `dfa_size_limit`,
+/// `nest_limit` and `line_terminator` (with `regex_fancy!`: `unicode`, `case_insensitive`, `multi_line`, `dot_matches_new_line`,
+/// `crlf`, `ignore_whitespace`, `backtrack_limit`, `delegate_size_limit` and `delegate_dfa_size_limit`). For example:
+/// ```rust
+/// use rust_regex_dsl::regex;
+///
+/// let regex = regex!("^[a-z]+$", case_insensitive = true, size_limit = 1_000_000);
+/// assert!(regex.is_match("Hello"));
+/// ```
+/// Is equivalent to:
+/// ```rust
+/// use regex::RegexBuilder;
+///
+/// let regex = RegexBuilder::new("^[a-z]+$").case_insensitive(true).size_limit(1_000_000).build().unwrap();
+/// assert!(regex.is_match("Hello"));
+/// ```
+/// But this will fail the compilation, as `\w{50}` is too big for such a small size limit:
+/// ```compile_fail
+/// use rust_regex_dsl::regex;
+///
+/// let regex = regex!("\\w{50}", size_limit = 1_000);
+/// ```
 #[proc_macro]
 pub fn regex(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as LitStr);
+    let (is_static, input, options) = parse_macro_input!(input with parse_static_literal);
+
+    if let Some(error) = literal_error(&input, Flavor::Str, &options) {
+        return error.into();
+    }
+    let dsl = Dsl::new(&input.value(), input.value().len() > 1);
+    let error_factory = ErrorFactory::new_obj(input.span());
+    build(&dsl, is_static, Flavor::Str, &options, error_factory)
+}
+
+/// A simple bytes regular expression macro.
+///
+/// This is the same as the [regex!] macro, but the result will be a valid [`regex::bytes::Regex`], that can match arbitrary bytes (and not only UTF-8 text).
+/// As with [regex!], `regex_bytes!(static "...")` will return a `&'static` [`regex::bytes::Regex`].
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl::regex_bytes;
+///
+/// let regex = regex_bytes!("(?-u:\\xFF)[a-z]+");
+/// assert!(regex.is_match(b"\xFFabc"));
+/// assert!(!regex.is_match(b"abc"));
+/// ```
+#[proc_macro]
+pub fn regex_bytes(input: TokenStream) -> TokenStream {
+    let (is_static, input, options) = parse_macro_input!(input with parse_static_literal);
+
+    if let Some(error) = literal_error(&input, Flavor::Bytes, &options) {
+        return error.into();
+    }
+    let dsl = Dsl::new(&input.value(), input.value().len() > 1);
+    let error_factory = ErrorFactory::new_obj(input.span());
+    build(&dsl, is_static, Flavor::Bytes, &options, error_factory)
+}
+
+/// A simple fancy regular expression macro.
+///
+/// This is the same as the [regex!] macro (with the `fancy` feature), but the result will be a [`fancy_regex::Regex`](https://docs.rs/fancy-regex),
+/// that supports look-around and backreferences. As with [regex!], `regex_fancy!(static "...")` will return a `&'static` regular expression.
+///
+/// For example:
+/// ```ignore
+/// use rust_regex_dsl::regex_fancy;
+///
+/// let regex = regex_fancy!("(\\w+) \\1");
+/// assert!(regex.is_match("hello hello").unwrap());
+/// assert!(!regex.is_match("hello world").unwrap());
+/// ```
+#[cfg(feature = "fancy")]
+#[proc_macro]
+pub fn regex_fancy(input: TokenStream) -> TokenStream {
+    let (is_static, input, options) = parse_macro_input!(input with parse_static_literal);
 
     let dsl = Dsl::new(&input.value(), input.value().len() > 1);
     let error_factory = ErrorFactory::new_obj(input.span());
-    dsl.build(error_factory).into()
+    build(&dsl, is_static, Flavor::Fancy, &options, error_factory)
 }
 
 /// A DSL for creating Regular Expressions.
@@ -60,6 +219,7 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// This macro will introduce a domain specific language to create a regular expression that is easier to read.
 ///
 /// The macro support a few types of arguments, if the macro has more than one argument, it will concat all the arguments.
+/// If the first argument is `static`, the result will be a `&'static` [`regex::Regex`] that is compiled only once (See [regex!]).
 ///
 /// The supported arguments are:
 /// # A literal string.
@@ -104,6 +264,19 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// assert!(regex.is_match("aα"));
 /// assert!(!regex.is_match("αa"));
 /// ```
+/// A class can also be a property with a value - `#<name> = <value>` - this is equivalent to `\p{<name>=<value>}`.
+/// The name and the value can be a string if they are not identifiers. For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl!{
+///     #Script = Greek,
+///     #"Decimal Number",
+///     ~Script = Latin,
+/// };
+/// assert!(regex.is_match("α1-"));
+/// assert!(!regex.is_match("α1a"));
+/// ```
 /// # Not a class name
 /// That is - `~<class_name>` - anything but a character from a unicode class. See more details in [here](https://www.regular-expressions.info/unicode.html). This is equivalent to `\P{<class_name>}`. For example:
 /// ```rust
@@ -131,6 +304,13 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// * `not_word_boundary` - Not a word boundary. This is equivalent to `\B`.
 /// * `beginning_of_input` - A beginning of the input. This is equivalent to `\A`.
 /// * `end_of_input` - An end of the input. This is equivalent to `\z`.
+/// * `word_start` - A beginning of a word. This is equivalent to `\b{start}`.
+/// * `word_end` - An end of a word. This is equivalent to `\b{end}`.
+/// * `word_start_half` - A beginning of a word, without a word character after it. This is equivalent to `\b{start-half}`.
+/// * `word_end_half` - An end of a word, without a word character before it. This is equivalent to `\b{end-half}`.
+/// * `ascii_<name>` - An ASCII class, where the name is one of: `alnum`, `alpha`, `blank`, `cntrl`, `digit`, `graph`, `lower`, `print`, `punct`, `space`, `upper`, `word` or `xdigit`. This is equivalent to `[[:<name>:]]`.
+/// * `ascii` - An ASCII character. This is equivalent to `[[:ascii:]]`.
+/// * `not_ascii_<name>` and `not_ascii` - Anything but the ASCII class. This is equivalent to `[[:^<name>:]]`.
 ///
 /// For example:
 /// ```rust
@@ -372,6 +552,22 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// assert!(regex.is_match("6"));
 /// assert!(regex.is_match("7"));
 /// ```
+/// * `any_of(<user_class>)` and `not_any_of(<user_class>)` - A nested class (or a reverted nested class). For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl! {
+///     any_of {
+///         '5',
+///         not_any_of {
+///            ascii_digit,
+///         }
+///     }
+/// };
+/// assert!(regex.is_match("5"));
+/// assert!(!regex.is_match("6"));
+/// assert!(regex.is_match("a"));
+/// ```
 /// ## `not_any_of`
 /// Revert a user class. The arguments are the same as the user class above. For example:
 /// ```rust
@@ -423,6 +619,22 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// let caps = regex.captures("|4|").unwrap();
 /// assert_eq!(&caps["a_digit"], "4");
 /// ```
+/// A named group can also have a `type:<type>` argument, to be used by the [create_capture!] macro (it is ignored by this macro). For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl! {
+///     "|",
+///     group {
+///         name: a_digit,
+///         type: u8,
+///         digit,
+///     },
+///     "|"
+/// };
+/// let caps = regex.captures("|4|").unwrap();
+/// assert_eq!(&caps["a_digit"], "4");
+/// ```
 ///
 /// One can have more than one group in an expression. For example:
 /// ```rust
@@ -639,6 +851,8 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// * `multi_line` - When the flag is set, `beginning_of_line` and end_of_line match begin/end of line and not input - equivalent to the `m` flag.
 /// * `allow_dot` - When the flag is set, allow `any_character` to match a new line - equivalent to the `s` flag.
 /// * `enables_crlf_mode` - When `multi_line` flag is set, `\r\n` is used - - equivalent to the `R` flag.
+/// * `swap_greed` - When the flag is set, repetitions are lazy unless `lazy` is used - equivalent to the `U` flag.
+/// * `unicode` - When the flag is unset, classes and characters are ASCII only - equivalent to the `u` flag.
 ///
 /// To set a flag, use: `+<flag>`, to unset it, use: `-<flag>`.
 /// One can apply flags on a specific DSL, by adding the DSL as an argument to the apply function - for example:
@@ -674,12 +888,151 @@ pub fn regex(input: TokenStream) -> TokenStream {
 /// assert!(!regex.is_match("fooBAR"));
 /// ```
 ///
+/// ## `use_fragment`
+/// Use a fragment that was defined by [define_dsl!]. For example:
+/// ```rust
+/// use rust_regex_dsl::{define_dsl, regex_dsl};
+///
+/// define_dsl!(hex_digit, any_of { #digit, from: 'a' to: 'f' });
+///
+/// let regex = regex_dsl! {
+///     "0x",
+///     repeat {
+///         use_fragment(hex_digit)
+///     }
+/// };
+/// assert!(regex.is_match("0x1f"));
+/// assert!(!regex.is_match("0xg"));
+/// ```
+///
+/// ## Options
+/// As with [regex!], the DSL can be followed by builder options (as `name = value`). For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl! {
+///     "hello",
+///     case_insensitive = true,
+/// };
+/// assert!(regex.is_match("HeLLo"));
+/// ```
+///
+/// ## Examples
+/// The DSL can also be followed by `examples { matches: [...], rejects: [...] }`. The regular expression will be matched against
+/// the examples during the compilation, and any example that is not matched (in `matches`) or is matched (in `rejects`)
+/// will fail the compilation with an error that points to the example. For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl! {
+///     beginning_of_line,
+///     repeat { any_of { from: 'a' to: 'z' } },
+///     digit,
+///     end_of_line,
+///     examples {
+///         matches: ["foo1", "a2"],
+///         rejects: ["bar", "foo12"],
+///     }
+/// };
+/// ```
+/// But this will fail the compilation, as `foo` has no digit:
+/// ```compile_fail
+/// use rust_regex_dsl::regex_dsl;
+///
+/// let regex = regex_dsl! {
+///     repeat { any_of { from: 'a' to: 'z' } },
+///     digit,
+///     examples { matches: ["foo"] }
+/// };
+/// ```
+/// The same can be used with [regex!], [regex_bytes!], [regex_dsl_bytes!] (where the examples can also be byte strings) and [create_capture!].
+///
+/// ## Look-around
+/// The regex crate does not support look-around, so `followed_by`, `not_followed_by`, `preceded_by` and `not_preceded_by`
+/// will fail the compilation with an error that explain how to get the same result without them.
+///
+/// ## Fancy
+/// With the `fancy` feature, `regex_dsl_fancy!`, `regex_fancy!` and [create_capture!] with the `fancy` mode will produce a
+/// [`fancy_regex::Regex`](https://docs.rs/fancy-regex) instead of a [`regex::Regex`] (this macro and [regex!] are not changed by the feature), and one can also use:
+/// * `backreference(<name>)` - Match the same text as the named group `<name>` matched. This is equivalent to `\k<name>`.
+/// * `lookahead { <dsl> }` - The DSL must follow the current position. This is equivalent to `(?=...)`.
+/// * `negative_lookahead { <dsl> }` - The DSL must not follow the current position. This is equivalent to `(?!...)`.
+/// * `lookbehind { <dsl> }` - The DSL must precede the current position. This is equivalent to `(?<=...)`.
+/// * `negative_lookbehind { <dsl> }` - The DSL must not precede the current position. This is equivalent to `(?<!...)`.
+///
+/// Without the `fancy` feature, those will fail the compilation.
+///
+/// For example (with the `fancy` feature):
+/// ```ignore
+/// use rust_regex_dsl::regex_dsl_fancy;
+///
+/// let regex = regex_dsl_fancy! {
+///     group {
+///         name: word,
+///         repeat { word_character }
+///     },
+///     " ",
+///     backreference(word),
+/// };
+/// assert!(regex.is_match("hello hello").unwrap());
+/// ```
+///
 #[proc_macro]
 pub fn regex_dsl(input: TokenStream) -> TokenStream {
-    let dsls: Vec<Dsl> = parse_macro_input!(input with parse_list_to_vec);
-    let dsl = Dsl::concat(&dsls);
-    let error_factory = ErrorFactory::new_root();
-    dsl.build(error_factory).into()
+    with_fragments(input, quote! { rust_regex_dsl::regex_dsl }, |input| {
+        let (is_static, dsls, options) = parse_macro_input!(input with parse_static_dsls);
+        let dsl = Dsl::concat(&dsls);
+        let error_factory = ErrorFactory::new_root();
+        build(&dsl, is_static, Flavor::Str, &options, error_factory)
+    })
+}
+
+/// A DSL for creating fancy Regular Expressions.
+///
+/// This is the same as the [regex_dsl!] macro (with the `fancy` feature), but the result will be a [`fancy_regex::Regex`](https://docs.rs/fancy-regex),
+/// so one can also use look-around and backreferences (see [regex_dsl!] for the functions). Note that with a [`fancy_regex::Regex`](https://docs.rs/fancy-regex),
+/// matching can fail in run time (for example, when the backtrack limit is exceeded), so the functions return a `Result`.
+#[cfg(feature = "fancy")]
+#[proc_macro]
+pub fn regex_dsl_fancy(input: TokenStream) -> TokenStream {
+    with_fragments(input, quote! { rust_regex_dsl::regex_dsl_fancy }, |input| {
+        let (is_static, dsls, options) = parse_macro_input!(input with parse_static_dsls);
+        let dsl = Dsl::concat(&dsls);
+        let error_factory = ErrorFactory::new_root();
+        build(&dsl, is_static, Flavor::Fancy, &options, error_factory)
+    })
+}
+
+/// A DSL for creating bytes Regular Expressions.
+///
+/// This is the same as the [regex_dsl!] macro, but the result will be a valid [`regex::bytes::Regex`], that can match arbitrary bytes (and not only UTF-8 text).
+/// With this macro, one can also use:
+/// * `byte(<number>)` - A single byte (that might not be a valid UTF-8). This is equivalent to `(?-u:\xNN)`.
+/// * `any_byte` - Any byte (including new line). This is equivalent to `(?s-u:.)`.
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl::regex_dsl_bytes;
+///
+/// let regex = regex_dsl_bytes! {
+///     byte(0xFF),
+///     times {
+///         exactly: 2,
+///         any_byte
+///     },
+///     "end"
+/// };
+/// assert!(regex.is_match(b"\xFF\x00\x80end"));
+/// assert!(!regex.is_match(b"\xFE\x00\x80end"));
+/// ```
+#[proc_macro]
+pub fn regex_dsl_bytes(input: TokenStream) -> TokenStream {
+    with_fragments(input, quote! { rust_regex_dsl::regex_dsl_bytes }, |input| {
+        let (is_static, dsls, options) = parse_macro_input!(input with parse_static_dsls);
+        let dsl = Dsl::concat(&dsls);
+        let error_factory = ErrorFactory::new_root();
+        build(&dsl, is_static, Flavor::Bytes, &options, error_factory)
+    })
 }
 
 /// A macro to create a Regular Expression capturing struct.
@@ -690,17 +1043,41 @@ pub fn regex_dsl(input: TokenStream) -> TokenStream {
 /// either as raw regular expression or as a DSL (See [regex_dsl!] for syntax).
 ///
 /// The struct will be a tuple with the first filed as a `&str` with the entire capture text (i.e. capture group 0) and the rest will be
-/// `Option<&str>` with the content of the capture group (for any capture group in the regular expression). That is, for `[a-z]+` the struct will have
-/// only `(&str)` and for `([a-z]+)([0-9]+)([a-z]+)` the struct will have `(&str, Option<&str>, Option<&str>, Option<&str>)`. If the regular expression has any named
+/// the content of the capture group (for any capture group in the regular expression). A capture group that is part of any match of the regular expression
+/// will be a `&str` and a capture group that might not be part of a match (for example, a group inside a `maybe` or an `any`) will be an `Option<&str>`.
+/// That is, for `[a-z]+` the struct will have only `(&str)`, for `([a-z]+)([0-9]+)([a-z]+)` the struct will have `(&str, &str, &str, &str)` and
+/// for `([a-z]+)|([0-9]+)` the struct will have `(&str, Option<&str>, Option<&str>)`. If the regular expression has any named
 /// capture groups, the struct will have a public function to retrieve them with the same name as the group name (snake case). The struct will have a `get_capture` method that will return the first member of the tuple.
+/// The last member of the tuple holds the byte ranges of the capture groups in the haystack. The struct will have a `get_capture_range` method that will return
+/// the range of the entire capture and a `<name>_range` method for any named capture group (that return an `Option` if the group might not be part of a match).
 ///
 /// The struct will have three static public functions:
 /// * `catch` that accept a string reference and return an Option with the struct if it matches the argument.
 /// * `catch_all` that accept a string reference and return an Iterator over all the places the expression was caught in the argument.
 /// * `regex` that return a reference to the regular expression.
 ///
+/// If the second argument is `bytes` (i.e. `create_capture!(Name, bytes, ...)`), the regular expression will be a [`regex::bytes::Regex`] and the struct
+/// will have `&[u8]` instead of `&str` (See [regex_dsl_bytes!] for the bytes only DSL functions). Typed groups are not supported with `bytes`.
+///
+/// If the second argument is `fancy` (i.e. `create_capture!(Name, fancy, ...)`, with the `fancy` feature), the regular expression will be a
+/// [`fancy_regex::Regex`](https://docs.rs/fancy-regex), so it can use look-around and backreferences (See `regex_dsl_fancy!`). As matching with it can fail
+/// in run time, `catch` will return a `Result<Option<Name>, fancy_regex::Error>` and `catch_all` will return an Iterator over `Result<Name, fancy_regex::Error>`.
+///
+/// If the second argument is `owned` (i.e. `create_capture!(Name, owned, ...)`), the macro will also create a `NameOwned` struct, that has the same
+/// members and functions as the capture struct, but with `String` instead of `&str`, so it can be kept after the haystack is dropped.
+/// The capture struct will have an `into_owned` function to convert it (as well as a [`From`] implementation).
+///
+/// If the second argument is `enum` (i.e. `create_capture!(Name, enum, any { ... })`), the macro will create an enum instead of a struct.
+/// The regular expression must be an `any` of named groups, and every group will be a variant of the enum (with the group name as upper camel case)
+/// holding the content of the group. The enum will have a `get_capture` method that return the content of the group, and the same static functions as the struct.
+/// Typed groups and `owned` are not supported with `enum`.
+///
 /// Please note, The capturing groups are optionals for regular expressions like: `([a-z]+)|([0-9]+)`.
 ///
+/// If a named group has a type (i.e. `group { name: <name>, type: <type>, ... }`), the function to retrieve it will parse the
+/// group content using [`std::str::FromStr`] and will return a `Result<type, <type as FromStr>::Err>` (or a `Result<Option<type>, <type as FromStr>::Err>`
+/// if the group might not be part of a match).
+///
 /// For example:
 /// ```rust
 /// use rust_regex_dsl::create_capture;
@@ -711,7 +1088,7 @@ pub fn regex_dsl(input: TokenStream) -> TokenStream {
 /// let caught = MyRegexCapture::catch("33").unwrap();
 /// assert_eq!(caught.0, "33");
 /// assert_eq!(caught.get_capture(), "33");
-/// assert_eq!(caught.1, Some("33"));
+/// assert_eq!(caught.1, "33");
 ///
 /// let all: Vec<_> = MyRegexCapture::catch_all("100 90 80").collect();
 /// assert_eq!(all.len(), 3);
@@ -747,8 +1124,10 @@ pub fn regex_dsl(input: TokenStream) -> TokenStream {
 /// assert_eq!(caught.get_capture(), "hello");
 /// assert_eq!(caught.1, Some("hello"));
 /// assert_eq!(caught.letters(), Some("hello"));
+/// assert_eq!(caught.letters_range(), Some(0..5));
 /// assert_eq!(caught.2, None);
 /// assert_eq!(caught.digits(), None);
+/// assert_eq!(caught.digits_range(), None);
 ///
 /// let caught = MyRegexCapture::catch("321").unwrap();
 /// assert_eq!(caught.0, "321");
@@ -765,10 +1144,361 @@ pub fn regex_dsl(input: TokenStream) -> TokenStream {
 /// assert_eq!(all[2].letters(), Some("B"));
 /// assert_eq!(all[3].digits(), Some("33"));
 /// ```
+/// An example with a typed group:
+/// ```rust
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Address,
+///   group {
+///     name: host,
+///     repeat {
+///       any_of {
+///         #letter,
+///         '.'
+///       }
+///     }
+///   },
+///   ':',
+///   group {
+///     name: port,
+///     type: u16,
+///     repeat {
+///       digit
+///     }
+///   });
+///
+/// let caught = Address::catch("localhost:8080").unwrap();
+/// assert_eq!(caught.host(), "localhost");
+/// assert_eq!(caught.port(), Ok(8080));
+/// assert_eq!(caught.get_capture_range(), 0..14);
+/// assert_eq!(caught.port_range(), 10..14);
+///
+/// let caught = Address::catch("localhost:100000").unwrap();
+/// assert!(caught.port().is_err());
+/// ```
+/// An example with an owned struct:
+/// ```rust
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Word, owned, group {
+///     name: word,
+///     repeat {
+///         #letter
+///     }
+/// });
+///
+/// let words: Vec<WordOwned> = {
+///     let haystack = String::from("Hello world");
+///     Word::catch_all(&haystack).map(Word::into_owned).collect()
+/// };
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(words[0].word(), "Hello");
+/// assert_eq!(words[1].get_capture(), "world");
+/// ```
+/// An example with an enum:
+/// ```rust
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Token, enum, any {
+///     group {
+///         name: number,
+///         repeat {
+///             #digit
+///         }
+///     },
+///     group {
+///         name: word,
+///         repeat {
+///             #letter
+///         }
+///     },
+///     group {
+///         name: operator,
+///         any_of {
+///             '+', '-', '*', '/'
+///         }
+///     }
+/// });
+///
+/// let tokens: Vec<_> = Token::catch_all("x + 12").collect();
+/// assert_eq!(tokens, vec![Token::Word("x"), Token::Operator("+"), Token::Number("12")]);
+/// for token in tokens {
+///     match token {
+///         Token::Number(number) => assert_eq!(number, "12"),
+///         Token::Word(word) => assert_eq!(word, "x"),
+///         Token::Operator(operator) => assert_eq!(operator, "+"),
+///     }
+/// }
+/// ```
+///
+/// As with [regex!], the expression can be followed by builder options (as `name = value`):
+/// ```rust
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Greeting, "hello (?<name>[a-z]+)", case_insensitive = true);
+///
+/// assert_eq!(Greeting::catch("Hello World").unwrap().name(), "World");
+/// ```
+///
+/// ## Sample
+/// With the `sample` feature, the struct (or enum) will also have a `sample(rng)` function that returns a random haystack
+/// that the regular expression matches (a `Vec<u8>` with `bytes`), to be used as a fixture, or `None` if no such haystack
+/// was found (i.e. if the assertions, like `\b`, can not be satisfied). The repetitions in the sample are bounded. The
+/// function is not created if the regular expression uses look-around or backreferences, or with the `octal` and
+/// `line_terminator` options.
+///
+/// For example (with the `sample` feature):
+/// ```ignore
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Pair, "(?<key>[a-z]+)=(?<value>[0-9]{1,3})");
+///
+/// let mut rng = rand::rng();
+/// let haystack = Pair::sample(&mut rng).unwrap();
+/// let pair = Pair::catch(&haystack).unwrap();
+/// assert!(pair.value().len() <= 3);
+/// ```
+///
+/// ## Proptest
+/// With the `proptest` feature, the struct (or enum) will also have a `strategy()` function that returns a
+/// [proptest](https://docs.rs/proptest) strategy of haystacks (a `Vec<u8>` with `bytes`) that the regular expression
+/// matches. As with `sample`, the function is not created if the regular expression uses look-around or
+/// backreferences, or with the `octal` and `line_terminator` options.
+///
+/// For example (with the `proptest` feature):
+/// ```ignore
+/// use proptest::proptest;
+/// use rust_regex_dsl::create_capture;
+///
+/// create_capture!(Pair, "^(?<key>[a-z]+)=(?<value>[0-9]{1,3})$");
+///
+/// proptest! {
+///     #[test]
+///     fn value_is_a_number(haystack in Pair::strategy()) {
+///         let pair = Pair::catch(&haystack).unwrap();
+///         assert!(pair.value().parse::<u16>().is_ok());
+///     }
+/// }
+/// ```
 ///
 #[proc_macro]
 pub fn create_capture(input: TokenStream) -> TokenStream {
-    let create_capture = parse_macro_input!(input as CreateCapture);
-    let error_factory = ErrorFactory::new_root();
-    create_capture.build(error_factory).into()
+    with_fragments(input, quote! { rust_regex_dsl::create_capture }, |input| {
+        let create_capture = parse_macro_input!(input as CreateCapture);
+        let error_factory = ErrorFactory::new_root();
+        create_capture.build(error_factory).into()
+    })
+}
+
+/// A derive macro to capture a regular expression into a user defined struct.
+///
+/// This is similar to [create_capture!], but the struct is defined by the user. The regular expression is defined using
+/// the `regex_dsl` attribute (See [regex_dsl!] for syntax) and every named capture group in the regular expression must have a field with the
+/// same name (snake case) in the struct (and every field must have a capture group), otherwise the compilation will fail.
+///
+/// The type of the field can be:
+/// * `&str` - for the content of the capture group.
+/// * `Option<&str>` - for the content of a capture group that might not be part of a match (for example, a group inside a `maybe` or an `any`).
+///   Using a `&str` for such a group will fail the compilation.
+/// * Any type that implements [`std::str::FromStr`] (or an `Option` of such type) - the content of the capture group will be parsed into that type.
+///   A match in which a field can not be parsed is treated as no match (that is, `catch` will return `None` and `catch_all` will skip it).
+/// * `Result<T, <T as FromStr>::Err>` (or `Result<Option<T>, <T as FromStr>::Err>`) - to keep the matches in which the capture group can not be
+///   parsed, with the parse error in the field.
+///
+/// The `regex_dsl` attribute can end with [`regex::RegexBuilder`] options (See [regex!]), for example: `#[regex_dsl(..., case_insensitive = true)]`.
+///
+/// The struct can have at most a single lifetime parameter, that will be used as the lifetime of the haystack.
+///
+/// The struct will have three static public functions:
+/// * `catch` that accept a string reference and return an Option with the struct if it matches the argument.
+/// * `catch_all` that accept a string reference and return an Iterator over all the places the expression was caught in the argument.
+/// * `regex` that return a reference to the regular expression.
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl::RegexCapture;
+///
+/// #[derive(RegexCapture, Debug, Clone)]
+/// #[regex_dsl(
+///     '[',
+///     group {
+///         name: level,
+///         repeat {
+///             #letter
+///         }
+///     },
+///     "] ",
+///     maybe {
+///         concat {
+///             group {
+///                 name: code,
+///                 repeat {
+///                     digit
+///                 }
+///             },
+///             ": ",
+///         }
+///     },
+///     group {
+///         name: message,
+///         maybe_repeat {
+///             any_character
+///         }
+///     }
+/// )]
+/// pub struct LogLine<'a> {
+///     /// The log level
+///     pub level: &'a str,
+///     pub code: Option<u32>,
+///     pub message: String,
+/// }
+///
+/// let line = LogLine::catch("[ERROR] 404: Not found").unwrap();
+/// assert_eq!(line.level, "ERROR");
+/// assert_eq!(line.code, Some(404));
+/// assert_eq!(line.message, "Not found");
+///
+/// let line = LogLine::catch("[INFO] Started").unwrap();
+/// assert_eq!(line.level, "INFO");
+/// assert_eq!(line.code, None);
+/// assert_eq!(line.message, "Started");
+/// ```
+/// But this:
+/// ```compile_fail
+/// use rust_regex_dsl::RegexCapture;
+///
+/// #[derive(RegexCapture)]
+/// #[regex_dsl(
+///     group {
+///         name: level,
+///         repeat {
+///             #letter
+///         }
+///     },
+/// )]
+/// pub struct LogLine<'a> {
+///     pub level: &'a str,
+///     pub code: u32,
+/// }
+/// ```
+/// will fail with compilation error because there is no `code` capture group.
+#[proc_macro_derive(RegexCapture, attributes(regex_dsl))]
+pub fn derive_regex_capture(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    RegexCapture::new(input).build().into()
+}
+
+/// A macro to compile a few DSLs into a single [`regex::RegexSet`] with typed results.
+///
+/// The first argument is the name of an enum to create, the rest are the variants of the enum. Each variant is a name
+/// followed by a colon and a DSL (See [regex_dsl!] for syntax). Every DSL is validated on compile time.
+///
+/// The enum will have three functions:
+/// * `matches` that accept a string reference and return an Iterator over all the variants that match it (in the order they were defined).
+/// * `regex_set` that return a reference to the regular expression set.
+/// * `pattern` that return the regular expression of the variant.
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl::regex_set_dsl;
+///
+/// regex_set_dsl!(LogLevel,
+///     Error: "[ERROR]",
+///     Warning: concat {
+///         "[WARN",
+///         maybe {
+///             "ING"
+///         },
+///         "]"
+///     },
+///     Timeout: concat {
+///         word_boundary,
+///         any {
+///             "timeout",
+///             "timed out",
+///         },
+///         word_boundary,
+///     }
+/// );
+///
+/// let levels: Vec<_> = LogLevel::matches("[ERROR] Connection timed out").collect();
+/// assert_eq!(levels, vec![LogLevel::Error, LogLevel::Timeout]);
+///
+/// let levels: Vec<_> = LogLevel::matches("[WARNING] Retrying").collect();
+/// assert_eq!(levels, vec![LogLevel::Warning]);
+///
+/// assert_eq!(LogLevel::matches("[INFO] Started").count(), 0);
+/// assert_eq!(LogLevel::Error.pattern(), "\\[ERROR\\]");
+/// assert_eq!(LogLevel::regex_set().len(), 3);
+/// ```
+#[proc_macro]
+pub fn regex_set_dsl(input: TokenStream) -> TokenStream {
+    with_fragments(input, quote! { rust_regex_dsl::regex_set_dsl }, |input| {
+        let regex_set = parse_macro_input!(input as RegexSet);
+        let error_factory = ErrorFactory::new_root();
+        regex_set.build(error_factory).into()
+    })
+}
+
+/// A macro to define a reusable DSL fragment.
+///
+/// The first argument is the name of the fragment and the rest is the DSL (See [regex_dsl!] for syntax). The fragment is validated on compile time.
+/// A defined fragment can be used by [regex_dsl!], [regex_dsl_bytes!], [create_capture!], [regex_set_dsl!] and other fragments with the `use_fragment(<name>)` function.
+///
+/// The fragment is defined as a `macro_rules!` macro with the same name (that is used by the other macros to find the fragment), so it follows the same scoping rules,
+/// i.e. it must be defined before it is used. The [RegexCapture] derive macro does not support fragments.
+///
+/// For example:
+/// ```rust
+/// use rust_regex_dsl::{create_capture, define_dsl, regex_dsl};
+///
+/// define_dsl!(octet, any {
+///     concat { "25", any_of { from: '0' to: '5' } },
+///     concat { '2', any_of { from: '0' to: '4' }, digit },
+///     concat { maybe { any_of { '0', '1' } }, digit, maybe { digit } },
+/// });
+/// define_dsl!(ipv4,
+///     use_fragment(octet),
+///     times {
+///         exactly: 3,
+///         concat { '.', use_fragment(octet) }
+///     }
+/// );
+///
+/// let regex = regex_dsl! {
+///     beginning_of_input,
+///     use_fragment(ipv4),
+///     end_of_input,
+/// };
+/// assert!(regex.is_match("127.0.0.1"));
+/// assert!(!regex.is_match("256.0.0.1"));
+///
+/// create_capture!(Route, concat {
+///     "from ",
+///     group { name: from, use_fragment(ipv4) },
+///     " to ",
+///     group { name: to, use_fragment(ipv4) },
+/// });
+/// let route = Route::catch("from 10.0.0.1 to 10.0.0.254").unwrap();
+/// assert_eq!(route.from(), "10.0.0.1");
+/// assert_eq!(route.to(), "10.0.0.254");
+/// ```
+/// But this:
+/// ```compile_fail
+/// use rust_regex_dsl::define_dsl;
+///
+/// define_dsl!(invalid, regex("[a-z"));
+/// ```
+/// will fail with compilation error because the fragment is not a valid regular expression.
+#[proc_macro]
+pub fn define_dsl(input: TokenStream) -> TokenStream {
+    let fragments = Fragments::new(input.into());
+    if let Some(splice) = fragments.splice_missing(quote! { rust_regex_dsl::define_dsl }) {
+        return splice.into();
+    }
+    let input = fragments.input().into();
+    fragments.with(|| {
+        let define_dsl = parse_macro_input!(input as DefineDsl);
+        define_dsl.build(fragments.preamble()).into()
+    })
 }
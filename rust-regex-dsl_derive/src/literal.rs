@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+use proc_macro2::Span;
+use syn::LitStr;
+
+/// The span of a part (a byte range of the value) of a string literal. This is only supported by a nightly compiler,
+/// so this will return `None` on stable.
+pub(crate) fn subspan(literal: &LitStr, range: Range<usize>) -> Option<Span> {
+    let token = literal.token();
+    let range = source_range(&token.to_string(), range)?;
+    token.subspan(range)
+}
+
+/// Map a byte range of the value of a string literal to the byte range of the same part in the source of the literal
+/// (i.e. in `"a\\(b"` the value range `1..2` is the source range `2..4`).
+fn source_range(source: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let offsets = value_offsets(source)?;
+    Some(*offsets.get(range.start)?..*offsets.get(range.end)?)
+}
+
+/// The offset in the source of every byte of the value (and of the closing quote).
+fn value_offsets(source: &str) -> Option<Vec<usize>> {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let start = 1 + hashes + 1;
+        let end = source.len().checked_sub(1 + hashes)?;
+        return Some((start..=end).collect());
+    }
+    let body = source.strip_prefix('"')?;
+    let mut offsets = vec![];
+    let mut chars = body.char_indices().peekable();
+    while let Some((index, chr)) = chars.next() {
+        let offset = index + 1;
+        match chr {
+            '"' => {
+                offsets.push(offset);
+                return Some(offsets);
+            }
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                let length = match escaped {
+                    'x' => {
+                        chars.next()?;
+                        chars.next()?;
+                        1
+                    }
+                    'u' => {
+                        let mut code = String::new();
+                        for (_, chr) in chars.by_ref() {
+                            match chr {
+                                '{' | '_' => {}
+                                '}' => break,
+                                chr => code.push(chr),
+                            }
+                        }
+                        char::from_u32(u32::from_str_radix(&code, 16).ok()?)?.len_utf8()
+                    }
+                    // A line continuation, the new line and the white spaces after it are skipped.
+                    '\n' => {
+                        while chars.next_if(|(_, chr)| chr.is_whitespace()).is_some() {}
+                        0
+                    }
+                    _ => 1,
+                };
+                offsets.extend(std::iter::repeat_n(offset, length));
+            }
+            chr => offsets.extend(std::iter::repeat_n(offset, chr.len_utf8())),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::source_range;
+
+    #[test]
+    fn plain_literal() {
+        // "[a-z][a-zA-Z_*"
+        assert_eq!(source_range("\"[a-z][a-zA-Z_*\"", 5..6), Some(6..7));
+        assert_eq!(source_range("\"(\"", 0..1), Some(1..2));
+        assert_eq!(source_range("\"(\"", 1..1), Some(2..2));
+    }
+
+    #[test]
+    fn escapes() {
+        // The value is `a\(b`
+        assert_eq!(source_range("\"a\\\\(b\"", 1..2), Some(2..4));
+        assert_eq!(source_range("\"a\\\\(b\"", 2..3), Some(4..5));
+        // The value is a tab, a quote and `x`
+        assert_eq!(source_range("\"\\t\\\"\\x78(\"", 2..4), Some(5..10));
+        // The value is `é(`
+        assert_eq!(source_range("\"\\u{e9}(\"", 2..3), Some(7..8));
+        assert_eq!(source_range("\"é(\"", 0..2), Some(1..3));
+    }
+
+    #[test]
+    fn line_continuation() {
+        // The value is `ab(`
+        assert_eq!(source_range("\"a\\\n    b(\"", 2..3), Some(9..10));
+    }
+
+    #[test]
+    fn raw_literal() {
+        assert_eq!(source_range("r\"\\p{Foo}\"", 0..7), Some(2..9));
+        assert_eq!(source_range("r#\"a\"b(\"#", 3..4), Some(6..7));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        assert_eq!(source_range("\"(\"", 0..3), None);
+        assert_eq!(source_range("b\"(\"", 0..1), None);
+    }
+}
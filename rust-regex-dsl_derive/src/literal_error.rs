@@ -0,0 +1,121 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use regex_syntax::{Error as SyntaxError, Parser, ParserBuilder};
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::options::RegexOptions;
+use syn::{Error, LitStr};
+
+use crate::literal::subspan;
+
+type BoolOption = fn(&mut ParserBuilder, bool) -> &mut ParserBuilder;
+
+/// The options that change the way the regular expression is parsed.
+const BOOL_OPTIONS: [(&str, BoolOption); 8] = [
+    ("unicode", ParserBuilder::unicode),
+    ("case_insensitive", ParserBuilder::case_insensitive),
+    ("multi_line", ParserBuilder::multi_line),
+    ("dot_matches_new_line", ParserBuilder::dot_matches_new_line),
+    ("crlf", ParserBuilder::crlf),
+    ("swap_greed", ParserBuilder::swap_greed),
+    ("ignore_whitespace", ParserBuilder::ignore_whitespace),
+    ("octal", ParserBuilder::octal),
+];
+
+/// The error of a regular expression literal that can not be parsed: the kind of the error on the part of the literal
+/// that caused it (or on the entire literal if the compiler can not point to a part of it, i.e. on stable), followed by
+/// a note with the rendered pattern. Returns `None` if the regular expression can be parsed (other errors, like a too
+/// big regular expression, are reported when the regular expression is built).
+pub fn literal_error(
+    literal: &LitStr,
+    flavor: Flavor,
+    options: &RegexOptions,
+) -> Option<TokenStream> {
+    #[cfg(feature = "fancy")]
+    if flavor == Flavor::Fancy {
+        // fancy_regex has its own syntax.
+        return None;
+    }
+    let error = parser(flavor, options).parse(&literal.value()).err()?;
+    let (kind, span) = match &error {
+        SyntaxError::Parse(error) => (error.kind().to_string(), error.span()),
+        SyntaxError::Translate(error) => (error.kind().to_string(), error.span()),
+        _ => return None,
+    };
+    let span = subspan(literal, span.start.offset..span.end.offset).unwrap_or(literal.span());
+    let mut result = Error::new(span, &kind);
+    // The rendered error ends with the kind, that is already reported.
+    let rendered = error.to_string();
+    let rendered = rendered
+        .strip_suffix(&format!("\nerror: {}", kind))
+        .unwrap_or(&rendered);
+    let note = flavor.with_fancy_hint(format!("note: {}", rendered), &literal.value());
+    result.combine(Error::new(literal.span(), note));
+    // There are two errors, and the result is an expression.
+    let errors = result.to_compile_error();
+    Some(quote! { { #errors } })
+}
+
+/// A parser that parses the regular expression the same way the builder will (with the options that change the way the
+/// regular expression is parsed).
+pub fn parser(flavor: Flavor, options: &RegexOptions) -> Parser {
+    let mut builder = ParserBuilder::new();
+    builder.utf8(flavor.utf8());
+    for (name, set) in BOOL_OPTIONS {
+        if let Some(yes) = options.bool_value(name) {
+            set(&mut builder, yes);
+        }
+    }
+    if let Some(limit) = options.number_value("nest_limit") {
+        builder.nest_limit(limit as u32);
+    }
+    if let Some(byte) = options.number_value("line_terminator") {
+        builder.line_terminator(byte as u8);
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::literal_error;
+    use rust_regex_dsl_core::flavor::Flavor;
+    use rust_regex_dsl_core::options::RegexOptions;
+    use syn::LitStr;
+
+    fn messages(regex: &str) -> Option<Vec<String>> {
+        let literal: LitStr = syn::parse_str(&format!("{:?}", regex)).unwrap();
+        let errors = literal_error(&literal, Flavor::Str, &RegexOptions::default())?;
+        let errors: syn::Block = syn::parse2(errors).unwrap();
+        let messages = errors
+            .stmts
+            .iter()
+            .map(|stmt| match stmt {
+                syn::Stmt::Macro(stmt) => stmt.mac.parse_body::<LitStr>().unwrap().value(),
+                _ => panic!("Not an error"),
+            })
+            .collect();
+        Some(messages)
+    }
+
+    #[test]
+    fn valid_regex() {
+        assert_eq!(messages("[a-z]+"), None);
+    }
+
+    #[test]
+    fn kind_and_note() {
+        // Without a proc macro there are no sub spans, so both errors are on the entire literal.
+        let messages = messages("[a-z][a-zA-Z_*").unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], "unclosed character class");
+        assert!(messages[1].starts_with("note: regex parse error:"));
+        assert!(messages[1].contains("[a-z][a-zA-Z_*"));
+        assert!(!messages[1].contains("unclosed character class"));
+    }
+
+    #[test]
+    fn translate_error() {
+        let messages = messages("\\p{Foo}").unwrap();
+        assert_eq!(messages[0], "Unicode property not found");
+        assert!(messages[1].contains("\\p{Foo}"));
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use regex_syntax::hir::{Hir, HirKind};
+
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::options::RegexOptions;
+
+use crate::literal_error::parser;
+
+pub struct Participation {
+    always: HashSet<usize>,
+}
+
+impl Participation {
+    /// The groups that always participate in a match, with the same options that are used to build the regular
+    /// expression (i.e. with `ignore_whitespace`, `( a )` has a group that always participates).
+    pub fn new(regex: &str, flavor: Flavor, options: &RegexOptions) -> Self {
+        let mut always = HashSet::new();
+        #[cfg(feature = "fancy")]
+        if flavor == Flavor::Fancy {
+            // The options of fancy_regex can all be expressed as inline flags.
+            let regex = format!("{}{}", options.inline_flags().unwrap_or_default(), regex);
+            if let Ok(tree) = fancy_regex::Expr::parse_tree(&regex) {
+                collect_always_participating_fancy(&tree.expr, true, &mut 0, &mut always);
+            }
+            return Participation { always };
+        }
+        if let Ok(hir) = parser(flavor, options).parse(regex) {
+            collect_always_participating(&hir, &mut always);
+        }
+        Participation { always }
+    }
+
+    pub fn always_participate(&self, index: usize) -> bool {
+        self.always.contains(&index)
+    }
+}
+
+fn collect_always_participating(hir: &Hir, always: &mut HashSet<usize>) {
+    match hir.kind() {
+        HirKind::Capture(capture) => {
+            always.insert(capture.index as usize);
+            collect_always_participating(&capture.sub, always);
+        }
+        HirKind::Concat(hirs) => {
+            for hir in hirs {
+                collect_always_participating(hir, always);
+            }
+        }
+        HirKind::Repetition(repetition) if repetition.min > 0 => {
+            collect_always_participating(&repetition.sub, always);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(feature = "fancy")]
+fn collect_always_participating_fancy(
+    expr: &fancy_regex::Expr,
+    participate: bool,
+    index: &mut usize,
+    always: &mut HashSet<usize>,
+) {
+    use fancy_regex::{Expr, LookAround};
+    match expr {
+        Expr::Group(sub) => {
+            *index += 1;
+            if participate {
+                always.insert(*index);
+            }
+            collect_always_participating_fancy(sub, participate, index, always);
+        }
+        Expr::Concat(exprs) => {
+            for expr in exprs {
+                collect_always_participating_fancy(expr, participate, index, always);
+            }
+        }
+        Expr::Repeat { child, lo, .. } => {
+            collect_always_participating_fancy(child, participate && *lo > 0, index, always);
+        }
+        Expr::LookAround(sub, LookAround::LookAhead | LookAround::LookBehind) => {
+            collect_always_participating_fancy(sub, participate, index, always);
+        }
+        _ => {
+            // Still need to count the groups in the other expressions.
+            for child in expr.children_iter() {
+                collect_always_participating_fancy(child, false, index, always);
+            }
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, GenericParam, Ident,
+    Lifetime, PathArguments, Result, Type,
+};
+
+use crate::participation::Participation;
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::functions::parse_list::parse_list_to_vec_with_options;
+use rust_regex_dsl_core::options::RegexOptions;
+use rust_regex_dsl_core::Dsl;
+
+enum FieldKind {
+    Str,
+    OptionalStr,
+    Parsed,
+    OptionalParsed,
+    ParseResult,
+    OptionalParseResult,
+}
+
+struct CaptureField {
+    name: Ident,
+    kind: FieldKind,
+    index: usize,
+}
+
+pub struct RegexCapture {
+    input: DeriveInput,
+}
+
+impl RegexCapture {
+    pub fn new(input: DeriveInput) -> Self {
+        RegexCapture { input }
+    }
+
+    pub fn build(&self) -> TokenStream {
+        match self.try_build() {
+            Ok(tokens) => tokens,
+            Err(e) => e.to_compile_error(),
+        }
+    }
+
+    fn try_build(&self) -> Result<TokenStream> {
+        let name = &self.input.ident;
+        let lifetime = self.lifetime()?;
+        let (regex_str, options) = self.regex()?;
+        let fields = self.fields(&regex_str, &options)?;
+
+        let haystack_lifetime = match &lifetime {
+            Some(lifetime) => lifetime.clone(),
+            None => Lifetime::new("'h", name.span()),
+        };
+        let method_generics = match &lifetime {
+            Some(_) => quote! {},
+            None => quote! { <#haystack_lifetime> },
+        };
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+        let new_regex = options.new_regex(Flavor::Str, &regex_str);
+        let assign_fields: Vec<_> = fields
+            .iter()
+            .map(|field| {
+                let name = &field.name;
+                let index = field.index;
+                let value = match field.kind {
+                    FieldKind::Str => quote! {
+                        captures.get(#index)?.as_str()
+                    },
+                    FieldKind::OptionalStr => quote! {
+                        captures.get(#index).map(|c| c.as_str())
+                    },
+                    FieldKind::Parsed => quote! {
+                        captures.get(#index)?.as_str().parse().ok()?
+                    },
+                    FieldKind::OptionalParsed => quote! {
+                        match captures.get(#index) {
+                            Some(c) => Some(c.as_str().parse().ok()?),
+                            None => None,
+                        }
+                    },
+                    FieldKind::ParseResult => quote! {
+                        captures.get(#index)?.as_str().parse()
+                    },
+                    FieldKind::OptionalParseResult => quote! {
+                        captures.get(#index).map(|c| c.as_str().parse()).transpose()
+                    },
+                };
+                quote! { #name: #value, }
+            })
+            .collect();
+
+        Ok(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                fn from_captures #method_generics(captures: rust_regex_dsl::Captures<#haystack_lifetime>) -> Option<Self> {
+                    Some(Self {
+                        #(#assign_fields)*
+                    })
+                }
+                pub fn catch #method_generics(haystack: &#haystack_lifetime str) -> Option<Self> {
+                    Self::regex().captures(haystack).and_then(Self::from_captures)
+                }
+                pub fn catch_all #method_generics(haystack: &#haystack_lifetime str) -> impl Iterator<Item = Self> + #haystack_lifetime {
+                    Self::regex().captures_iter(haystack).filter_map(Self::from_captures)
+                }
+                pub fn regex() -> &'static rust_regex_dsl::Regex {
+                    static REGEX: std::sync::LazyLock<rust_regex_dsl::Regex> = std::sync::LazyLock::new(|| #new_regex);
+                    &REGEX
+                }
+            }
+        })
+    }
+
+    fn lifetime(&self) -> Result<Option<Lifetime>> {
+        let mut lifetime = None;
+        for param in &self.input.generics.params {
+            match param {
+                GenericParam::Lifetime(param) if lifetime.is_none() => {
+                    lifetime = Some(param.lifetime.clone());
+                }
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Only a single lifetime parameter is supported",
+                    ))
+                }
+            }
+        }
+        Ok(lifetime)
+    }
+
+    fn regex(&self) -> Result<(String, RegexOptions)> {
+        let Some(attr) = self
+            .input
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("regex_dsl"))
+        else {
+            return Err(Error::new(
+                self.input.ident.span(),
+                "Missing regex_dsl attribute",
+            ));
+        };
+        let (items, options): (Vec<Dsl>, _) =
+            attr.parse_args_with(parse_list_to_vec_with_options)?;
+        if items.is_empty() {
+            return Err(Error::new(attr.span(), "Nothing to capture"));
+        }
+        options.check(Flavor::Str)?;
+        let dsl = Dsl::concat(&items);
+        if let Some(err) = dsl.validate_with(Flavor::Str, &options) {
+            return Err(Error::new(attr.span(), err));
+        }
+        options.check_examples(Flavor::Str, dsl.as_str())?;
+        Ok((dsl.as_str().to_string(), options))
+    }
+
+    fn fields(&self, regex: &str, options: &RegexOptions) -> Result<Vec<CaptureField>> {
+        let Data::Struct(data) = &self.input.data else {
+            return Err(Error::new(
+                self.input.ident.span(),
+                "Only a struct with named fields is supported",
+            ));
+        };
+        let Fields::Named(fields) = &data.fields else {
+            return Err(Error::new(
+                self.input.ident.span(),
+                "Only a struct with named fields is supported",
+            ));
+        };
+        let participation = Participation::new(regex, Flavor::Str, options);
+        // The regular expression was already validated with the options.
+        let names: Vec<_> = options
+            .capture_names(Flavor::Str, regex)
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, name)| name.map(|name| (i, name.to_case(Case::Snake))))
+            .collect();
+
+        let mut errors: Option<Error> = None;
+        let mut add_error = |error: Error| match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        };
+        let mut capture_fields = vec![];
+        for field in &fields.named {
+            let Some(name) = &field.ident else {
+                continue;
+            };
+            let field_name = name.to_string();
+            let field_name = field_name.trim_start_matches("r#");
+            let Some((index, _)) = names.iter().find(|(_, n)| n == field_name) else {
+                add_error(Error::new(
+                    name.span(),
+                    format!("No capture group named {}", field_name),
+                ));
+                continue;
+            };
+            let kind = field_kind(&field.ty);
+            let optional = matches!(
+                kind,
+                FieldKind::OptionalStr | FieldKind::OptionalParsed | FieldKind::OptionalParseResult
+            );
+            if !optional && !participation.always_participate(*index) {
+                add_error(Error::new(
+                    field.ty.span(),
+                    format!(
+                        "The capture group {} might not be part of a match, use an Option",
+                        field_name
+                    ),
+                ));
+            }
+            capture_fields.push(CaptureField {
+                name: name.clone(),
+                kind,
+                index: *index,
+            });
+        }
+        for (index, name) in &names {
+            if !capture_fields.iter().any(|f| f.index == *index) {
+                add_error(Error::new(
+                    self.input.ident.span(),
+                    format!("No field for the capture group {}", name),
+                ));
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(capture_fields),
+        }
+    }
+}
+
+fn field_kind(ty: &Type) -> FieldKind {
+    if is_str(ty) {
+        return FieldKind::Str;
+    }
+    if let Some(ty) = generic_argument(ty, "Result", 2) {
+        return match option_of(ty) {
+            Some(_) => FieldKind::OptionalParseResult,
+            None => FieldKind::ParseResult,
+        };
+    }
+    match option_of(ty) {
+        Some(ty) if is_str(ty) => FieldKind::OptionalStr,
+        Some(_) => FieldKind::OptionalParsed,
+        None => FieldKind::Parsed,
+    }
+}
+
+fn is_str(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => match reference.elem.as_ref() {
+            Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn option_of(ty: &Type) -> Option<&Type> {
+    generic_argument(ty, "Option", 1)
+}
+
+/// The first generic argument of a type named `name` with exactly `count` generic arguments (i.e. `T` for `Option<T>`
+/// or `Result<T, E>`).
+fn generic_argument<'a>(ty: &'a Type, name: &str, count: usize) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(ty)) if args.args.len() == count => Some(ty),
+        _ => None,
+    }
+}
@@ -0,0 +1,91 @@
+use convert_case::{Case, Casing};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use regex::RegexSet as Set;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Colon;
+use syn::{Error, Ident, Result};
+
+use crate::error_factory::ErrorFactory;
+use rust_regex_dsl_core::flavor::Flavor;
+use rust_regex_dsl_core::functions::parse_list::{parse_list_to_vec, skip_comma};
+use rust_regex_dsl_core::Dsl;
+
+struct Variant {
+    name: Ident,
+    dsl: Dsl,
+}
+impl Parse for Variant {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        let _: Colon = input.parse()?;
+        let dsl: Dsl = input.parse()?;
+        if let Some(err) = dsl.validate(Flavor::Str) {
+            return Err(Error::new(name.span(), err));
+        }
+        Ok(Variant { name, dsl })
+    }
+}
+
+pub struct RegexSet {
+    enum_name: Ident,
+    variants: Vec<Variant>,
+}
+impl Parse for RegexSet {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let enum_name: Ident = input.parse()?;
+        skip_comma(input)?;
+        let variants: Vec<Variant> = parse_list_to_vec(input)?;
+        if variants.is_empty() {
+            return Err(Error::new(enum_name.span(), "Nothing to match"));
+        }
+        for (i, variant) in variants.iter().enumerate() {
+            if variants[..i].iter().any(|v| v.name == variant.name) {
+                return Err(Error::new(
+                    variant.name.span(),
+                    format!("Duplicate variant {}", variant.name),
+                ));
+            }
+        }
+        Ok(RegexSet {
+            enum_name,
+            variants,
+        })
+    }
+}
+impl RegexSet {
+    pub fn build(&self, error_factory: ErrorFactory) -> TokenStream {
+        let regexes: Vec<_> = self.variants.iter().map(|v| v.dsl.as_str()).collect();
+        if let Err(e) = Set::new(&regexes) {
+            return error_factory.error(format!("Invalid regular expression set: {}", e));
+        }
+        let enum_name = &self.enum_name;
+        let set_name = format_ident!(
+            "{}_REGEX_SET",
+            self.enum_name.to_string().to_case(Case::UpperSnake)
+        );
+        let names: Vec<_> = self.variants.iter().map(|v| &v.name).collect();
+        let len = names.len();
+        let lits: Vec<_> = regexes.iter().map(|r| Literal::string(r)).collect();
+        quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            enum #enum_name {
+                #(#names),*
+            }
+            static #set_name: std::sync::LazyLock<rust_regex_dsl::RegexSet> = std::sync::LazyLock::new(|| rust_regex_dsl::RegexSet::new([#(#lits),*]).unwrap());
+            impl #enum_name {
+                const VARIANTS: [#enum_name; #len] = [#(#enum_name::#names),*];
+
+                pub fn matches(haystack: &str) -> impl Iterator<Item = #enum_name> {
+                    #set_name.matches(haystack).into_iter().map(|i| Self::VARIANTS[i])
+                }
+                pub fn regex_set() -> &'static rust_regex_dsl::RegexSet {
+                    &*#set_name
+                }
+                pub fn pattern(&self) -> &'static str {
+                    &#set_name.patterns()[*self as usize]
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use rust_regex_dsl::{create_capture, regex_bytes, regex_dsl_bytes};
+
+#[test]
+fn simple_bytes_regex() {
+    let regex = regex_bytes!("(?-u:\\x00\\xFF)+");
+
+    assert!(regex.is_match(b"\x00\xFF\x00\xFF"));
+    assert!(!regex.is_match(b"\xFF\x00"));
+}
+
+#[test]
+fn bytes_dsl() {
+    let regex = regex_dsl_bytes! {
+        beginning_of_input,
+        byte(0x02),
+        maybe_repeat {
+            lazy,
+            any_byte
+        },
+        byte(3),
+        end_of_input
+    };
+
+    assert!(regex.is_match(b"\x02\x80\n\xFF\x03"));
+    assert!(regex.is_match(b"\x02\x03"));
+    assert!(!regex.is_match(b"\x02\x80"));
+    assert!(!regex.is_match(b"\x01\x03"));
+}
+
+#[test]
+fn bytes_capture() {
+    create_capture!(Frame, bytes, owned, byte(0xFE), group {
+        name: length,
+        any_byte
+    }, group {
+        name: payload,
+        repeat { lazy, any_byte }
+    }, maybe {
+        group {
+            name: checksum,
+            byte(0xAA)
+        }
+    }, byte(0xFF));
+
+    let frame = Frame::catch(b"\x00\xFE\x03\x01\x02\xFFrest").unwrap();
+    assert_eq!(frame.get_capture(), b"\xFE\x03\x01\x02\xFF");
+    assert_eq!(frame.length(), b"\x03");
+    assert_eq!(frame.payload(), b"\x01\x02");
+    assert_eq!(frame.checksum(), None);
+    assert_eq!(frame.payload_range(), 3..5);
+
+    let all: Vec<FrameOwned> = Frame::catch_all(b"\xFE\x01\x80\xAA\xFF\xFE\x02\x90\xFF")
+        .map(Frame::into_owned)
+        .collect();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].payload(), b"\x80");
+    assert_eq!(all[0].checksum(), Some(&b"\xAA"[..]));
+    assert_eq!(all[1].get_capture(), b"\xFE\x02\x90\xFF");
+    assert_eq!(all[1].checksum(), None);
+}
+
+#[test]
+fn static_bytes_regex() {
+    let regex = regex_bytes!(static "(?-u:\\xFF)");
+    assert!(regex.is_match(b"\x00\xFF"));
+
+    let regex = regex_dsl_bytes!(static byte(0xFE));
+    assert!(regex.is_match(b"\xFE"));
+    assert!(!regex.is_match(b"\xFF"));
+}
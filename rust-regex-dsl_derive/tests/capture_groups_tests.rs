@@ -24,10 +24,10 @@ fn two_groups_one_named() {
 
     let one = ThreeGroups::catch("123abc").unwrap();
     assert_eq!(one.0, "123abc");
-    assert_eq!(one.1, Some("123"));
-    assert_eq!(one.2, Some("abc"));
+    assert_eq!(one.1, "123");
+    assert_eq!(one.2, "abc");
     assert_eq!(one.get_capture(), "123abc");
-    assert_eq!(one.letters(), Some("abc"));
+    assert_eq!(one.letters(), "abc");
 
     let two = ThreeGroups::catch("ABC");
     assert!(two.is_none());
@@ -35,8 +35,8 @@ fn two_groups_one_named() {
     let three: Vec<_> = ThreeGroups::catch_all("1a22bb33cc").collect();
     assert_eq!(three.len(), 3);
     assert_eq!(three[0].0, "1a");
-    assert_eq!(three[1].1, Some("22"));
-    assert_eq!(three[2].letters(), Some("cc"));
+    assert_eq!(three[1].1, "22");
+    assert_eq!(three[2].letters(), "cc");
 }
 
 #[test]
@@ -45,8 +45,8 @@ fn use_regex() {
 
     let one = MyRegexCapture::catch("one abcd").unwrap();
     assert_eq!(one.0, "one abcd");
-    assert_eq!(one.1, Some("abcd"));
-    assert_eq!(one.a_name(), Some("abcd"));
+    assert_eq!(one.1, "abcd");
+    assert_eq!(one.a_name(), "abcd");
     assert_eq!(one.get_capture(), "one abcd");
 
     let two = MyRegexCapture::catch("ABC");
@@ -55,7 +55,7 @@ fn use_regex() {
     let three: Vec<_> = MyRegexCapture::catch_all("one a").collect();
     assert_eq!(three.len(), 1);
     assert_eq!(three[0].0, "one a");
-    assert_eq!(three[0].1, Some("a"));
+    assert_eq!(three[0].1, "a");
 }
 
 #[test]
@@ -105,3 +105,170 @@ fn get_regex() {
     assert!(regex.is_match("test"));
     assert!(!regex.is_match("TEST"));
 }
+#[test]
+fn typed_groups() {
+    create_capture!(Address, group {
+        name: host,
+        repeat { any_of { #letter, '.' } }
+    }, ':', group {
+        name: port,
+        type: u16,
+        repeat { digit }
+    });
+
+    let one = Address::catch("localhost:8080").unwrap();
+    assert_eq!(one.host(), "localhost");
+    assert_eq!(one.port(), Ok(8080));
+    assert_eq!(one.2, "8080");
+
+    let two = Address::catch("localhost:80800").unwrap();
+    assert!(two.port().is_err());
+}
+
+#[test]
+fn optional_groups() {
+    create_capture!(MaybeSign, group {
+        name: sign,
+        maybe { '-' }
+    }, maybe {
+        group {
+            name: minus,
+            '-'
+        }
+    }, group {
+        name: digits,
+        type: u32,
+        repeat { digit }
+    }, maybe_repeat {
+        group {
+            name: letter,
+            type: char,
+            #letter
+        }
+    });
+
+    let one = MaybeSign::catch("-12").unwrap();
+    assert_eq!(one.sign(), "-");
+    assert_eq!(one.minus(), None);
+    assert_eq!(one.digits(), Ok(12));
+    assert_eq!(one.letter(), Ok(None));
+
+    let two = MaybeSign::catch("--3ab").unwrap();
+    assert_eq!(two.sign(), "-");
+    assert_eq!(two.minus(), Some("-"));
+    assert_eq!(two.digits(), Ok(3));
+    assert_eq!(two.letter(), Ok(Some('b')));
+}
+
+#[test]
+fn owned_capture() {
+    create_capture!(Pair, owned, group {
+        name: key,
+        repeat { #letter }
+    }, '=', maybe {
+        group {
+            name: value,
+            type: u32,
+            repeat { digit }
+        }
+    });
+
+    let owned: Vec<PairOwned> = {
+        let haystack = String::from("a=1 b= c=3");
+        Pair::catch_all(&haystack).map(Pair::into_owned).collect()
+    };
+    assert_eq!(owned.len(), 3);
+    assert_eq!(owned[0].get_capture(), "a=1");
+    assert_eq!(owned[0].key(), "a");
+    assert_eq!(owned[0].value(), Ok(Some(1)));
+    assert_eq!(owned[1].key(), "b");
+    assert_eq!(owned[1].value(), Ok(None));
+    assert_eq!(owned[2].1, "c");
+    assert_eq!(owned[2].2, Some("3".to_string()));
+
+    let from = PairOwned::from(Pair::catch("d=4").unwrap());
+    assert_eq!(
+        from,
+        PairOwned(
+            "d=4".into(),
+            "d".into(),
+            Some("4".into()),
+            [Some(0..3), Some(0..1), Some(2..3)]
+        )
+    );
+    assert_eq!(from.value_range(), Some(2..3));
+}
+
+#[test]
+fn ranges() {
+    create_capture!(Assignment, group {
+        name: name,
+        repeat { #letter }
+    }, '=', any {
+        group {
+            name: number,
+            repeat { digit }
+        },
+        group {
+            name: text,
+            repeat { #letter }
+        }
+    });
+
+    let one = Assignment::catch("let a=12;").unwrap();
+    assert_eq!(one.get_capture_range(), 4..8);
+    assert_eq!(one.name_range(), 4..5);
+    assert_eq!(one.number_range(), Some(6..8));
+    assert_eq!(one.text_range(), None);
+
+    let all: Vec<_> = Assignment::catch_all("a=1 bc=de").collect();
+    assert_eq!(all[1].get_capture_range(), 4..9);
+    assert_eq!(all[1].name_range(), 4..6);
+    assert_eq!(all[1].number_range(), None);
+    assert_eq!(all[1].text_range(), Some(7..9));
+}
+
+create_capture!(Lexeme, enum, any {
+    group {
+        name: identifier,
+        concat {
+            #letter,
+            maybe_repeat {
+                word_character
+            }
+        }
+    },
+    group {
+        name: int_number,
+        repeat {
+            #digit
+        }
+    },
+    group {
+        name: punctuation,
+        any_of {
+            '(', ')', ','
+        }
+    }
+});
+
+#[test]
+fn enum_capture() {
+    let lexemes: Vec<_> = Lexeme::catch_all("max(a1, 20)").collect();
+    assert_eq!(
+        lexemes,
+        vec![
+            Lexeme::Identifier("max"),
+            Lexeme::Punctuation("("),
+            Lexeme::Identifier("a1"),
+            Lexeme::Punctuation(","),
+            Lexeme::IntNumber("20"),
+            Lexeme::Punctuation(")"),
+        ]
+    );
+    assert_eq!(lexemes[4].get_capture(), "20");
+
+    assert_eq!(Lexeme::catch("  42"), Some(Lexeme::IntNumber("42")));
+    assert!(Lexeme::catch("  ").is_none());
+    assert!(Lexeme::regex().is_match("x"));
+}
@@ -0,0 +1,70 @@
+use rust_regex_dsl::{create_capture, regex, regex_bytes, regex_dsl, regex_dsl_bytes};
+
+#[test]
+fn regex_with_examples() {
+    let regex = regex!(
+        "^[a-z]+[0-9]$",
+        examples {
+            matches: ["foo1", "a2"],
+            rejects: ["bar", "1"],
+        }
+    );
+    assert!(regex.is_match("foo1"));
+}
+
+#[test]
+fn regex_dsl_with_examples() {
+    let regex = regex_dsl! {
+        beginning_of_line,
+        repeat { any_of { from: 'a' to: 'z' } },
+        digit,
+        end_of_line,
+        examples { matches: ["foo1"], rejects: ["bar"] }
+    };
+    assert!(regex.is_match("foo1"));
+}
+
+#[test]
+fn examples_with_options() {
+    let regex = regex_dsl! {
+        "foo",
+        case_insensitive = true,
+        examples { matches: ["FOO"] },
+    };
+    assert!(regex.is_match("Foo"));
+}
+
+#[test]
+fn only_rejects() {
+    let regex = regex!(static "^a", examples { rejects: ["ba"] });
+    assert!(regex.is_match("ab"));
+}
+
+#[test]
+fn bytes_examples() {
+    let regex = regex_bytes!(
+        "(?-u:\\xFF)",
+        examples {
+            matches: [b"\xFF"],
+            rejects: ["\u{FF}"]
+        }
+    );
+    assert!(regex.is_match(b"\xFF"));
+    let regex = regex_dsl_bytes! {
+        byte(0xFF),
+        examples { matches: [b"\x00\xFF"], rejects: [b"\xFE"] }
+    };
+    assert!(regex.is_match(b"\xFF"));
+}
+
+#[test]
+fn capture_with_examples() {
+    create_capture!(
+        Version,
+        group { name: major, repeat { digit } },
+        ".",
+        group { name: minor, repeat { digit } },
+        examples { matches: ["1.2", "v10.20"], rejects: ["1", "a.b"] }
+    );
+    assert_eq!(Version::catch("v10.20").unwrap().minor(), "20");
+}
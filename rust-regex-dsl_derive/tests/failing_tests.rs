@@ -3,4 +3,8 @@ use trybuild::TestCases;
 fn ui() {
     let t = TestCases::new();
     t.compile_fail("tests/ui/*.rs");
+    #[cfg(not(feature = "fancy"))]
+    t.compile_fail("tests/ui/without_fancy/*.rs");
+    #[cfg(feature = "fancy")]
+    t.compile_fail("tests/ui/fancy/*.rs");
 }
@@ -0,0 +1,67 @@
+use rust_regex_dsl::{create_capture, define_dsl, regex_dsl, regex_dsl_bytes, regex_set_dsl};
+
+define_dsl!(number, repeat { #digit });
+define_dsl!(name, #letter, maybe_repeat { word_character });
+define_dsl!(assignment,
+    group {
+        name: key,
+        use_fragment(name)
+    },
+    '=',
+    group {
+        name: value,
+        type: u32,
+        use_fragment(number)
+    }
+);
+
+#[test]
+fn fragment_in_regex_dsl() {
+    let regex = regex_dsl! {
+        beginning_of_input,
+        use_fragment(number),
+        '.',
+        use_fragment(number),
+        end_of_input,
+    };
+    assert_eq!(regex.as_str(), "\\A\\p{digit}+\\.\\p{digit}+\\z");
+    assert!(regex.is_match("3.14"));
+    assert!(!regex.is_match("3."));
+}
+
+#[test]
+fn fragment_in_bytes_regex_dsl() {
+    let regex = regex_dsl_bytes!(static use_fragment(name), byte(0));
+    assert!(regex.is_match(b"abc\x00"));
+    assert!(!regex.is_match(b"1\x00"));
+}
+
+#[test]
+fn fragment_that_use_fragments() {
+    create_capture!(Assignment, use_fragment(assignment));
+
+    let caught = Assignment::catch("a1 = 10; b=20").unwrap();
+    assert_eq!(caught.key(), "b");
+    assert_eq!(caught.value(), Ok(20));
+}
+
+#[test]
+fn fragment_in_regex_set() {
+    regex_set_dsl!(Kind,
+        Number: concat { beginning_of_input, use_fragment(number), end_of_input },
+        Name: concat { beginning_of_input, use_fragment(name), end_of_input },
+    );
+
+    assert_eq!(Kind::matches("12").collect::<Vec<_>>(), vec![Kind::Number]);
+    assert_eq!(Kind::matches("a12").collect::<Vec<_>>(), vec![Kind::Name]);
+}
+
+mod inner {
+    use rust_regex_dsl::regex_dsl;
+
+    #[test]
+    fn fragment_in_inner_module() {
+        let regex = regex_dsl!(use_fragment(number));
+        assert!(regex.is_match("1"));
+    }
+}
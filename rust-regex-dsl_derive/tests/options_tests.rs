@@ -0,0 +1,78 @@
+use rust_regex_dsl::{create_capture, regex, regex_bytes, regex_dsl, regex_dsl_bytes};
+
+#[test]
+fn regex_with_options() {
+    let regex = regex!("^[a-z]+$", case_insensitive = true, size_limit = 1_000_000);
+    assert!(regex.is_match("Hello"));
+    assert!(!regex.is_match("Hello World"));
+}
+
+#[test]
+fn static_regex_with_options() {
+    let regex = regex!(static "^a.b$", dot_matches_new_line = true);
+    assert!(regex.is_match("a\nb"));
+}
+
+#[test]
+fn regex_dsl_with_options() {
+    let regex = regex_dsl! {
+        beginning_of_line,
+        "foo",
+        end_of_line,
+        multi_line = true,
+        case_insensitive = true,
+    };
+    assert!(regex.is_match("bar\nFOO\nbaz"));
+}
+
+#[test]
+fn swap_greed() {
+    let regex = regex_dsl! {
+        repeat { any_character },
+        swap_greed = true
+    };
+    assert_eq!(regex.find("abc").unwrap().as_str(), "a");
+}
+
+#[test]
+fn unicode_off() {
+    let regex = regex_bytes!("^\\w+$", unicode = false);
+    assert!(regex.is_match(b"abc"));
+    assert!(!regex.is_match("été".as_bytes()));
+    let regex = regex_dsl_bytes! {
+        repeat { word_character },
+        unicode = false,
+        line_terminator = 0
+    };
+    assert!(regex.is_match(b"abc"));
+}
+
+#[test]
+fn capture_with_options() {
+    create_capture!(Greeting, "hello (?<name>[a-z]+)", case_insensitive = true);
+    assert_eq!(Greeting::catch("Hello World").unwrap().name(), "World");
+
+    create_capture!(
+        Pair,
+        group { name: key, repeat { word_character } },
+        "=",
+        group { name: value, repeat { word_character } },
+        ignore_whitespace = false,
+        nest_limit = 10,
+    );
+    let pair = Pair::catch("a=b").unwrap();
+    assert_eq!(pair.key(), "a");
+    assert_eq!(pair.value(), "b");
+}
+
+#[test]
+fn capture_participation_with_options() {
+    // With ignore_whitespace the first group is part of a comment, so `word` always participates.
+    create_capture!(
+        Commented,
+        "# (x)?\n(?<word>[a-z]+)",
+        ignore_whitespace = true
+    );
+    let word: &str = Commented::catch("abc").unwrap().word();
+    assert_eq!(word, "abc");
+}
@@ -0,0 +1,159 @@
+use std::num::ParseIntError;
+
+use rust_regex_dsl::RegexCapture;
+
+#[derive(RegexCapture, Debug, Clone, PartialEq)]
+#[regex_dsl(
+    group {
+        name: key,
+        repeat { word_character }
+    },
+    '=',
+    group {
+        name: value,
+        repeat { digit }
+    },
+)]
+struct KeyValue<'a> {
+    key: &'a str,
+    value: u8,
+}
+
+#[test]
+fn borrowed_and_parsed_fields() {
+    let one = KeyValue::catch("a=10").unwrap();
+    assert_eq!(one.key, "a");
+    assert_eq!(one.value, 10);
+
+    assert!(KeyValue::catch("a=").is_none());
+    assert!(KeyValue::catch("a=1000").is_none());
+
+    let all: Vec<_> = KeyValue::catch_all("a=1 b=2000 c=3").collect();
+    assert_eq!(
+        all,
+        vec![
+            KeyValue { key: "a", value: 1 },
+            KeyValue { key: "c", value: 3 }
+        ]
+    );
+}
+
+#[derive(RegexCapture, Debug)]
+#[regex_dsl(
+    group {
+        name: key,
+        repeat { word_character }
+    },
+    '=',
+    group {
+        name: value,
+        repeat { digit }
+    },
+    maybe {
+        concat {
+            '/',
+            group {
+                name: limit,
+                repeat { digit }
+            }
+        }
+    }
+)]
+struct Limited<'a> {
+    key: &'a str,
+    value: Result<u8, ParseIntError>,
+    limit: std::result::Result<Option<u8>, ParseIntError>,
+}
+
+#[test]
+fn parse_errors() {
+    let one = Limited::catch("a=1000/3").unwrap();
+    assert_eq!(one.key, "a");
+    assert!(one.value.is_err());
+    assert_eq!(one.limit, Ok(Some(3)));
+
+    let two = Limited::catch("b=3/1000").unwrap();
+    assert_eq!(two.value, Ok(3));
+    assert!(two.limit.is_err());
+
+    let three = Limited::catch("c=3").unwrap();
+    assert_eq!(three.limit, Ok(None));
+}
+
+#[derive(RegexCapture, Debug)]
+#[regex_dsl(any {
+    group {
+        name: Letters,
+        repeat { #letter }
+    },
+    group {
+        name: digits,
+        repeat { digit }
+    }
+})]
+struct Token {
+    letters: Option<String>,
+    digits: Option<u64>,
+}
+
+#[test]
+fn owned_optional_fields() {
+    let one = Token::catch("abc").unwrap();
+    assert_eq!(one.letters, Some("abc".to_string()));
+    assert_eq!(one.digits, None);
+
+    let two = Token::catch("123").unwrap();
+    assert_eq!(two.letters, None);
+    assert_eq!(two.digits, Some(123));
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(
+    group {
+        name: name,
+        repeat { any_of { from: 'a' to: 'z' } }
+    },
+    case_insensitive = true
+)]
+struct Name {
+    name: String,
+}
+
+#[test]
+fn options() {
+    let name = Name::catch("Hello").unwrap();
+    assert_eq!(name.name, "Hello");
+    assert!(Name::regex().is_match("HELLO"));
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(regex("(?<first>[a-z]+) (?<second>[a-z]+)?"))]
+struct Words<'h> {
+    first: &'h str,
+    second: Option<&'h str>,
+}
+
+#[test]
+fn raw_regex() {
+    let one = Words::catch("one two").unwrap();
+    assert_eq!(one.first, "one");
+    assert_eq!(one.second, Some("two"));
+
+    let two = Words::catch("one ").unwrap();
+    assert_eq!(two.first, "one");
+    assert_eq!(two.second, None);
+
+    assert!(Words::regex().is_match("a b"));
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(regex("# (x)?\n(?<word>[a-z]+)"), ignore_whitespace = true)]
+struct Commented<'h> {
+    // With ignore_whitespace the first group is part of a comment, so this group always participates.
+    word: &'h str,
+}
+
+#[test]
+fn participation_with_options() {
+    assert_eq!(Commented::catch("abc").unwrap().word, "abc");
+}
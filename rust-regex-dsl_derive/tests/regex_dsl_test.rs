@@ -48,3 +48,18 @@ fn test_regex_sb() {
     assert!(!regex.is_match("|TEST|"));
     assert!(!regex.is_match("test"));
 }
+
+#[test]
+fn test_static_regex() {
+    let first = || {
+        regex_dsl! {
+            static,
+            "[",
+            repeat { digit },
+            "]",
+        }
+    };
+    assert!(first().is_match("[12]"));
+    assert!(!first().is_match("[]"));
+    assert!(std::ptr::eq(first(), first()));
+}
@@ -0,0 +1,48 @@
+use rust_regex_dsl::regex_set_dsl;
+
+regex_set_dsl!(Token,
+    Number: concat {
+        beginning_of_line,
+        repeat { digit },
+        end_of_line,
+    },
+    Word: concat {
+        beginning_of_line,
+        repeat { #letter },
+        end_of_line,
+    },
+    HasDigit: digit,
+    Empty: regex("^$"),
+);
+
+#[test]
+fn matches_return_all_the_matching_variants() {
+    let found: Vec<_> = Token::matches("123").collect();
+    assert_eq!(found, vec![Token::Number, Token::HasDigit]);
+
+    let found: Vec<_> = Token::matches("abc").collect();
+    assert_eq!(found, vec![Token::Word]);
+
+    let found: Vec<_> = Token::matches("a1").collect();
+    assert_eq!(found, vec![Token::HasDigit]);
+
+    let found: Vec<_> = Token::matches("").collect();
+    assert_eq!(found, vec![Token::Empty]);
+
+    assert_eq!(Token::matches("-").count(), 0);
+}
+
+#[test]
+fn regex_set_has_all_the_patterns() {
+    let set = Token::regex_set();
+    assert_eq!(set.len(), 4);
+    assert!(set.is_match("12"));
+    assert!(!set.is_match("?"));
+}
+
+#[test]
+fn pattern_return_the_variant_regex() {
+    assert_eq!(Token::Number.pattern(), "^\\d+$");
+    assert_eq!(Token::HasDigit.pattern(), "\\d");
+    assert_eq!(Token::Empty.pattern(), "^$");
+}
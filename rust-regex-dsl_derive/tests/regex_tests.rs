@@ -24,3 +24,14 @@ fn test_with_letters() {
     assert!(!regex.is_match("ACD"));
     assert!(!regex.is_match("abc"));
 }
+
+#[test]
+fn static_regex_is_compiled_once() {
+    let mut regexes = vec![];
+    for _ in 0..3 {
+        let regex: &'static regex::Regex = regex!(static "^[0-9]+$");
+        assert!(regex.is_match("123"));
+        regexes.push(regex);
+    }
+    assert!(std::ptr::eq(regexes[0], regexes[2]));
+}
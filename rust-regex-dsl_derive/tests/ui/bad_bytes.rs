@@ -0,0 +1,21 @@
+use rust_regex_dsl::{create_capture, regex_dsl, regex_dsl_bytes};
+
+fn main() {
+    let regex = regex_dsl! {
+        byte(0xFF)
+    };
+    println!("{}", regex);
+    let regex = regex_dsl_bytes! {
+        byte(256)
+    };
+    println!("{}", regex);
+    let regex = regex_dsl_bytes! {
+        byte("a")
+    };
+    println!("{}", regex);
+    create_capture!(Frame, bytes, group {
+        name: length,
+        type: u8,
+        any_byte
+    });
+}
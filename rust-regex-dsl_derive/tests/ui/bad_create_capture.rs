@@ -45,7 +45,14 @@ fn main() {
     println!("{}", regex);
     let regex = create_capture! {
         Test,
-        regex("(?<one>[a-z]+)(?<one>[a-z]+)"),
+        regex("(?<get_capture_range>[a-z]+)"),
     };
     println!("{}", regex);
-}
\ No newline at end of file
+    let regex = create_capture! {
+        Test,
+        bytes,
+        fancy,
+        "[a-z]+",
+    };
+    println!("{}", regex);
+}
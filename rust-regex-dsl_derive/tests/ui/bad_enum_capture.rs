@@ -0,0 +1,49 @@
+use rust_regex_dsl::create_capture;
+
+fn main() {
+    create_capture!(NotAny, enum, group {
+        name: one,
+        "1"
+    });
+
+    create_capture!(NotNamed, enum, any {
+        group {
+            name: one,
+            "1"
+        },
+        group {
+            "2"
+        }
+    });
+
+    create_capture!(NotGroup, enum, any {
+        group {
+            name: one,
+            "1"
+        },
+        "2"
+    });
+
+    create_capture!(Typed, enum, any {
+        group {
+            name: one,
+            type: u8,
+            "1"
+        },
+        group {
+            name: two,
+            "2"
+        }
+    });
+
+    create_capture!(Owned, enum, owned, any {
+        group {
+            name: one,
+            "1"
+        },
+        group {
+            name: two,
+            "2"
+        }
+    });
+}
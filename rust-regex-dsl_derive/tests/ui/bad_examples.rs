@@ -0,0 +1,27 @@
+use rust_regex_dsl::{create_capture, regex, regex_dsl};
+
+fn main() {
+    let regex = regex_dsl! {
+        beginning_of_line,
+        repeat { any_of { from: 'a' to: 'z' } },
+        digit,
+        end_of_line,
+        examples { matches: ["foo1", "foo"], rejects: ["bar", "bar2"] }
+    };
+    println!("{}", regex);
+    let regex = regex!("a", examples {});
+    println!("{}", regex);
+    let regex = regex!("a", examples { matches: "a" });
+    println!("{}", regex);
+    let regex = regex!("a", examples { matches: [1] });
+    println!("{}", regex);
+    let regex = regex!("a", examples { maybe: ["a"] });
+    println!("{}", regex);
+    let regex = regex!("a", examples { matches: ["a"], matches: ["a"] });
+    println!("{}", regex);
+    let regex = regex!("a", examples { matches: [b"a"] });
+    println!("{}", regex);
+    let regex = regex!("a", examples { matches: ["a"] }, examples { rejects: ["b"] });
+    println!("{}", regex);
+    create_capture!(Test, group { name: a, "a" }, examples { matches: ["b"] });
+}
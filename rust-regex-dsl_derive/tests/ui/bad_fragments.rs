@@ -0,0 +1,22 @@
+use rust_regex_dsl::{define_dsl, regex_dsl, RegexCapture};
+
+define_dsl!(empty);
+define_dsl!(invalid, regex("[a-z"));
+define_dsl!(bytes, byte(0xFF));
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, use_fragment(bytes) })]
+struct Derived<'a> {
+    name: &'a str,
+}
+
+fn main() {
+    let regex = regex_dsl! {
+        use_fragment(bytes)
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        use_fragment(unknown)
+    };
+    println!("{}", regex);
+}
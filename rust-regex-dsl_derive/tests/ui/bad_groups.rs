@@ -32,4 +32,11 @@ fn main() {
         }
     );
     println!("{}", regex);
-}
\ No newline at end of file
+    let regex = regex_dsl!(
+        group {
+            type: u16,
+            "test"
+        }
+    );
+    println!("{}", regex);
+}
@@ -0,0 +1,24 @@
+use rust_regex_dsl::{create_capture, regex, regex_dsl};
+
+fn main() {
+    let regex = regex!("\\w{50}", size_limit = 1_000);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", no_such_option = true);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", unicode = 1);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", size_limit = false);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", line_terminator = 256);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", unicode = true, unicode = false);
+    println!("{}", regex);
+    let regex = regex!("[a-z]", backtrack_limit = 10);
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        repeat { repeat { repeat { "a" } } },
+        nest_limit = 2
+    };
+    println!("{}", regex);
+    create_capture!(Big, group { name: word, times { exactly: 50, word_character } }, size_limit = 1_000);
+}
@@ -0,0 +1,50 @@
+use rust_regex_dsl::RegexCapture;
+
+#[derive(RegexCapture)]
+struct NoAttribute<'a> {
+    name: &'a str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, repeat { digit } })]
+struct Tuple<'a>(&'a str);
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, repeat { digit } })]
+struct MissingGroup<'a> {
+    name: &'a str,
+    other: &'a str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, repeat { digit } }, group { name: other, repeat { digit } })]
+struct MissingField<'a> {
+    name: &'a str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(maybe { group { name: name, repeat { digit } } })]
+struct NotOptional<'a> {
+    name: &'a str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, repeat { digit } })]
+struct TwoLifetimes<'a, 'b> {
+    name: &'a str,
+    other: &'b str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(regex("[a-z"))]
+struct BadRegex<'a> {
+    name: &'a str,
+}
+
+#[derive(RegexCapture)]
+#[regex_dsl(group { name: name, repeat { digit } }, backtrack_limit = 10)]
+struct BadOption<'a> {
+    name: &'a str,
+}
+
+fn main() {}
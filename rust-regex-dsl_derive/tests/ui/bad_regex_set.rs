@@ -0,0 +1,19 @@
+use rust_regex_dsl::regex_set_dsl;
+
+regex_set_dsl!(Empty);
+
+regex_set_dsl!(Duplicate,
+    One: "1",
+    One: "2",
+);
+
+regex_set_dsl!(Invalid,
+    One: "1",
+    Two: regex("("),
+);
+
+regex_set_dsl!(NoDsl,
+    One,
+);
+
+fn main() {}
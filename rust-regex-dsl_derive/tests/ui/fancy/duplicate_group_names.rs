@@ -0,0 +1,9 @@
+use rust_regex_dsl::create_capture;
+
+fn main() {
+    let regex = create_capture! {
+        Test,
+        regex("(?<one>[a-z]+)(?<one>[a-z]+)"),
+    };
+    println!("{}", regex);
+}
@@ -0,0 +1,12 @@
+use rust_regex_dsl::{create_capture, regex, regex_dsl};
+
+fn main() {
+    let regex = regex_dsl! {
+        "foo",
+        lookahead { "bar" }
+    };
+    println!("{}", regex);
+    let regex = regex!("(a)\\1");
+    println!("{}", regex);
+    create_capture!(Test, group { name: word, repeat { word_character } }, backreference(word));
+}
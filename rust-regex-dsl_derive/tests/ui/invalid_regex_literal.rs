@@ -0,0 +1,14 @@
+pub use rust_regex_dsl::{regex, regex_bytes};
+
+fn main() {
+    let regex = regex!("[a-z][a-zA-Z_*");
+    println!("{}", regex);
+    let regex = regex!(r"\p{NoSuchClass}+");
+    println!("{}", regex);
+    let regex = regex!("a b (", ignore_whitespace = true);
+    println!("{}", regex);
+    let regex = regex_bytes!("(?<name>a)(?<name>b)");
+    println!("{}", regex);
+    let regex = regex!("(?-u:\\xFF)");
+    println!("{}", regex);
+}
@@ -0,0 +1,24 @@
+use rust_regex_dsl::regex_dsl;
+
+fn main() {
+    let regex = regex_dsl! {
+        "foo",
+        followed_by { "bar" }
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        "foo",
+        not_followed_by { "bar" }
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        preceded_by { "bar" },
+        "foo"
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        not_preceded_by { "bar" },
+        "foo"
+    };
+    println!("{}", regex);
+}
@@ -0,0 +1,33 @@
+use rust_regex_dsl::{create_capture, regex_dsl};
+
+fn main() {
+    let regex = regex_dsl! {
+        group {
+            name: word,
+            repeat { word_character }
+        },
+        backreference(word)
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        "foo",
+        lookahead { "bar" }
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        "foo",
+        negative_lookahead { "bar" }
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        lookbehind { "bar" },
+        "foo"
+    };
+    println!("{}", regex);
+    let regex = regex_dsl! {
+        negative_lookbehind { "bar" },
+        "foo"
+    };
+    println!("{}", regex);
+    create_capture!(Fancy, fancy, "a");
+}
@@ -1,7 +1,13 @@
 //! This crate was build to help using hard coded regular expressions. It provides those macros:
 //! * The [`regex!`] macro - to verify a regular expression on compile time and remove the need to do it in run time and unwrap it.
 //! * The [`regex_dsl!`] macro - to make a regular expression easier to read and maintain.
+//! * The [`regex_bytes!`] and [`regex_dsl_bytes!`] macros - the same as the above, but for regular expressions that match arbitrary bytes.
 //! * The [`create_capture!`] macro  - to create a struct from a regular expression.
+//! * The [`RegexCapture`] derive macro - to capture a regular expression into a user defined struct.
+//! * The [`regex_set_dsl!`] macro - to match a few DSLs at once and get typed results.
+//! * The [`define_dsl!`] macro - to define a DSL fragment that can be reused by the other macros.
+//!
+//! It also provides the [`Dsl`] builder, to create regular expressions in run time using the same DSL.
 //!
 //! To use, add:
 //! ```toml
@@ -9,13 +15,52 @@
 //! ```
 //! To your `Cargo.toml` manifest.
 //!
+//! To use look-around and backreferences, enable the `fancy` feature. With it, the `regex_fancy!` and `regex_dsl_fancy!` macros and
+//! the `fancy` mode of [`create_capture!`] will produce a [`fancy_regex::Regex`](https://docs.rs/fancy-regex) (see [`regex_dsl!`] for more details).
+//!
+//! To parse a DSL text in run time, enable the `parse` feature. With it, the `parse_dsl` function is also provided (without
+//! it, the run time code only depends on [regex](https://docs.rs/regex)).
+//!
+//! To generate strings that match a regular expression, enable the `sample` feature. With it, [`create_capture!`] will also
+//! create a `sample(rng)` function (see [`create_capture!`] for more details).
+//!
+//! To fuzz with [proptest](https://docs.rs/proptest), enable the `proptest` feature. With it, [`create_capture!`] will
+//! also create a `strategy()` function (see [`create_capture!`] for more details).
+//!
 //! To build a DSL from a regular expression, see [rust-regex-dsl-creator](https://crates.io/crates/rust-regex-dsl-creator).
 
 #[doc(hidden)]
-pub use regex::{Captures, Regex};
+pub use regex::{Captures, Regex, RegexBuilder, RegexSet};
+#[doc(hidden)]
+pub mod bytes {
+    pub use regex::bytes::{Captures, Regex, RegexBuilder};
+}
+#[cfg(feature = "fancy")]
+#[doc(hidden)]
+pub mod fancy {
+    pub use fancy_regex::{Captures, Error, Regex, RegexBuilder};
+}
+#[cfg(feature = "parse")]
+pub use rust_regex_dsl_core::parse_dsl;
+pub use rust_regex_dsl_core::{Apply, Class, Dsl, DslError, Flag, Times};
 pub use rust_regex_dsl_derive::create_capture;
+pub use rust_regex_dsl_derive::define_dsl;
 pub use rust_regex_dsl_derive::regex;
+pub use rust_regex_dsl_derive::regex_bytes;
 pub use rust_regex_dsl_derive::regex_dsl;
+pub use rust_regex_dsl_derive::regex_dsl_bytes;
+pub use rust_regex_dsl_derive::regex_set_dsl;
+pub use rust_regex_dsl_derive::RegexCapture;
+#[cfg(feature = "fancy")]
+pub use rust_regex_dsl_derive::{regex_dsl_fancy, regex_fancy};
 
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub use proptest;
+#[cfg(feature = "sample")]
+#[doc(hidden)]
+pub use rand;
+#[cfg(feature = "sample")]
+pub use rust_regex_dsl_creator::Sampler;
 #[cfg(feature = "creator")]
-pub use rust_regex_dsl_creator::ToDsl;
+pub use rust_regex_dsl_creator::{RustOutput, ToDsl, ToDslError, ToExplanation};
@@ -0,0 +1,147 @@
+#![cfg(feature = "fancy")]
+
+use rust_regex_dsl::{create_capture, regex, regex_dsl, regex_dsl_fancy, regex_fancy};
+
+#[test]
+fn regex_is_fancy() {
+    let regex = regex_fancy!("(\\w+) \\1");
+    assert!(regex.is_match("hello hello").unwrap());
+    assert!(!regex.is_match("hello world").unwrap());
+}
+
+#[test]
+fn backreference() {
+    let regex = regex_dsl_fancy! {
+        beginning_of_line,
+        group {
+            name: word,
+            repeat { word_character }
+        },
+        " ",
+        backreference(word),
+        end_of_line,
+    };
+    assert!(regex.is_match("hello hello").unwrap());
+    assert!(!regex.is_match("hello world").unwrap());
+}
+
+#[test]
+fn lookahead() {
+    let regex = regex_dsl_fancy! {
+        repeat { digit },
+        lookahead { "px" },
+    };
+    assert_eq!(regex.find("10em 20px").unwrap().unwrap().as_str(), "20");
+}
+
+#[test]
+fn negative_lookahead() {
+    let regex = regex_dsl_fancy! {
+        word_boundary,
+        repeat { digit },
+        negative_lookahead { any { digit, "px" } },
+    };
+    assert_eq!(regex.find("20px 10em").unwrap().unwrap().as_str(), "10");
+}
+
+#[test]
+fn lookbehind() {
+    let regex = regex_dsl_fancy! {
+        lookbehind { "$" },
+        repeat { digit },
+    };
+    assert_eq!(regex.find("10 $20").unwrap().unwrap().as_str(), "20");
+}
+
+#[test]
+fn negative_lookbehind() {
+    let regex = regex_dsl_fancy! {
+        negative_lookbehind { any { "$", digit } },
+        repeat { digit },
+    };
+    assert_eq!(regex.find("$20 10").unwrap().unwrap().as_str(), "10");
+}
+
+#[test]
+fn static_regex() {
+    let regex = regex_dsl_fancy! {
+        static,
+        lookahead { "a" },
+        any_character,
+    };
+    assert!(regex.is_match("a").unwrap());
+    assert!(!regex.is_match("b").unwrap());
+}
+
+#[test]
+fn capture() {
+    create_capture!(
+        Price,
+        fancy,
+        lookbehind { "$" },
+        group {
+            name: amount,
+            repeat { digit }
+        },
+    );
+    let price = Price::catch("10 $20").unwrap().unwrap();
+    assert_eq!(price.amount(), "20");
+    assert_eq!(price.amount_range(), 4..6);
+    assert!(Price::catch("10 20").unwrap().is_none());
+    let all: Vec<_> = Price::catch_all("$1 2 $3")
+        .map(|price| price.unwrap().amount())
+        .collect();
+    assert_eq!(all, vec!["1", "3"]);
+}
+
+#[test]
+fn enum_capture() {
+    create_capture!(
+        Token,
+        fancy,
+        enum,
+        any {
+            group { name: number, repeat { digit } },
+            group { name: name, repeat { any_of { from: 'a' to: 'z' } } },
+        }
+    );
+    let tokens: Vec<_> = Token::catch_all("a1").map(Result::unwrap).collect();
+    assert_eq!(tokens, vec![Token::Name("a"), Token::Number("1")]);
+}
+
+#[test]
+fn options() {
+    let regex = regex_fancy!("(a)\\1", case_insensitive = true, backtrack_limit = 1_000);
+    assert!(regex.is_match("Aa").unwrap());
+}
+
+#[test]
+fn run_time_errors_are_returned() {
+    create_capture!(Slow, fancy, "(a+)+\\1b", backtrack_limit = 10);
+    let haystack = "a".repeat(20);
+    assert!(Slow::catch(&haystack).is_err());
+    assert!(Slow::catch_all(&haystack).next().unwrap().is_err());
+}
+
+#[test]
+fn participation_with_options() {
+    // With ignore_whitespace the first group is part of a comment, so `word` always participates.
+    create_capture!(
+        Commented,
+        fancy,
+        "# (x)?\n(?<word>[a-z]+)(?=!)",
+        ignore_whitespace = true
+    );
+    let word: &str = Commented::catch("abc!").unwrap().unwrap().word();
+    assert_eq!(word, "abc");
+}
+
+#[test]
+fn other_macros_are_not_fancy() {
+    let regex: &rust_regex_dsl::Regex = &regex!("a+");
+    assert!(regex.is_match("aa"));
+    let regex: &rust_regex_dsl::Regex = &regex_dsl! { repeat { "a" } };
+    assert!(regex.is_match("aa"));
+    create_capture!(Plain, "a+");
+    assert_eq!(Plain::catch("baa").unwrap().get_capture(), "aa");
+}
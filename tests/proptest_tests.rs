@@ -0,0 +1,55 @@
+#![cfg(feature = "proptest")]
+
+use rust_regex_dsl::create_capture;
+use rust_regex_dsl::proptest::prelude::*;
+
+create_capture!(Pair, "^(?<key>[a-z]+)=(?<value>[0-9]{1,3})$");
+
+create_capture!(
+    Word,
+    word_boundary,
+    group {
+        name: word,
+        repeat { ascii_alpha }
+    },
+    word_boundary,
+    case_insensitive = true,
+);
+
+create_capture!(Number, bytes, "(?-u:\\xFF)[0-9]+");
+
+create_capture!(Token, enum, "(?<number>[0-9]+)|(?<word>[a-z]+)");
+
+// Can not be generated, so there is no strategy function, but the capture is still created.
+create_capture!(Terminated, "a$", multi_line = true, line_terminator = 59);
+
+proptest! {
+    #[test]
+    fn pair_strategy(haystack in Pair::strategy()) {
+        let pair = Pair::catch(&haystack).unwrap();
+        prop_assert_eq!(pair.get_capture(), haystack.as_str());
+        prop_assert!(pair.value().parse::<u16>().unwrap() < 1000);
+    }
+
+    #[test]
+    fn strategy_with_options(haystack in Word::strategy()) {
+        let word = Word::catch(&haystack).unwrap();
+        prop_assert_eq!(word.word(), haystack.as_str());
+    }
+
+    #[test]
+    fn bytes_strategy(haystack in Number::strategy()) {
+        prop_assert_eq!(haystack[0], 0xFF);
+        prop_assert!(Number::catch(&haystack).is_some());
+    }
+
+    #[test]
+    fn enum_strategy(haystack in Token::strategy()) {
+        prop_assert!(Token::catch(&haystack).is_some());
+    }
+}
+
+#[test]
+fn capture_without_strategy() {
+    assert_eq!(Terminated::catch("a;").unwrap().get_capture(), "a");
+}
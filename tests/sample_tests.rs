@@ -0,0 +1,73 @@
+#![cfg(feature = "sample")]
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_regex_dsl::{create_capture, Sampler};
+
+create_capture!(Pair, "(?<key>[a-z]+)=(?<value>[0-9]{1,3})");
+
+create_capture!(Greeting, "hello (?<name>\\w+)", case_insensitive = true);
+
+create_capture!(Number, bytes, "(?-u:\\xFF)[0-9]+");
+
+create_capture!(Token, enum, "(?<number>[0-9]+)|(?<word>[a-z]+)");
+
+create_capture!(Impossible, "a\\bb");
+
+// Can not be sampled, so there is no sample function, but the capture is still created.
+create_capture!(Terminated, "a$", multi_line = true, line_terminator = 59);
+
+#[test]
+fn sample_matches_the_capture() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..50 {
+        let haystack = Pair::sample(&mut rng).unwrap();
+        let pair = Pair::catch(&haystack).unwrap();
+        assert_eq!(pair.get_capture(), haystack);
+        assert!((1..=3).contains(&pair.value().len()));
+    }
+}
+
+#[test]
+fn sample_uses_the_options() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let samples: Vec<_> = (0..50)
+        .map(|_| Greeting::sample(&mut rng).unwrap())
+        .collect();
+    assert!(samples
+        .iter()
+        .all(|sample| Greeting::catch(sample).is_some()));
+    assert!(samples.iter().any(|sample| !sample.starts_with("hello")));
+}
+
+#[test]
+fn sample_bytes() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let haystack = Number::sample(&mut rng).unwrap();
+    assert_eq!(haystack[0], 0xFF);
+    assert!(Number::catch(&haystack).is_some());
+}
+
+#[test]
+fn sample_enum() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let haystack = Token::sample(&mut rng).unwrap();
+    assert!(Token::catch(&haystack).is_some());
+}
+
+#[test]
+fn impossible_sample() {
+    let mut rng = StdRng::seed_from_u64(7);
+    assert!(Impossible::sample(&mut rng).is_none());
+}
+
+#[test]
+fn capture_without_sample() {
+    assert_eq!(Terminated::catch("a;").unwrap().get_capture(), "a");
+}
+
+#[test]
+fn sampler() {
+    let sampler = Sampler::new("[ab]{2}").unwrap();
+    assert_eq!(sampler.enumerate(10), vec!["aa", "ab", "ba", "bb"]);
+}